@@ -16,7 +16,7 @@ fn main() {
 
 fn build_digraphs() {
     let input_path = format!(
-        "{}/assets/raw_bogram_Table.html",
+        "{}/assets/raw_bogram_table.html",
         env!("CARGO_MANIFEST_DIR")
     );
     let output_path = format!("{}/src/digraphs.ron", env!("CARGO_MANIFEST_DIR"));
@@ -28,30 +28,27 @@ fn build_digraphs() {
 
     let mut digraphs: Vec<Digraph> = Vec::new();
 
-    for raw_line in lines {
-        if let Ok(line) = raw_line {
-            //println!("line: {}", line);
-            let start = line.find("title=");
-            if let Some(start) = start {
-                if let Some(end) = line.find(":") {
-                    let start = start + 7;
-                    let end = end;
-                    let title_slice = &line[start..end];
+    for line in lines.map_while(Result::ok) {
+        //println!("line: {}", line);
+        let start = line.find("title=");
+        if let Some(start) = start {
+            if let Some(end) = line.find(":") {
+                let start = start + 7;
+                let title_slice = &line[start..end];
 
-                    if let Some(percent) = line.find('%') {
-                        let start = end + 2;
-                        let end = percent;
-                        let f = &line[start..end].parse::<f32>();
-                        if let Ok(mut frequency) = f {
-                            frequency /= 100.0;
-                            let chars_raw: Vec<char> = title_slice
-                                .chars()
-                                .map(|x| x.to_ascii_lowercase())
-                                .collect();
-                            let chars: [char; 2] = chars_raw.as_slice().try_into().unwrap();
-                            if frequency > 0.00001 {
-                                digraphs.push(Digraph { chars, frequency });
-                            }
+                if let Some(percent) = line.find('%') {
+                    let start = end + 2;
+                    let end = percent;
+                    let f = &line[start..end].parse::<f32>();
+                    if let Ok(mut frequency) = f {
+                        frequency /= 100.0;
+                        let chars_raw: Vec<char> = title_slice
+                            .chars()
+                            .map(|x| x.to_ascii_lowercase())
+                            .collect();
+                        let chars: [char; 2] = chars_raw.as_slice().try_into().unwrap();
+                        if frequency > 0.00001 {
+                            digraphs.push(Digraph { chars, frequency });
                         }
                     }
                 }
@@ -66,7 +63,7 @@ fn build_digraphs() {
         .enumerate_arrays(true);
     let s = to_string_pretty(&digraphs, pretty.clone()).expect("Serialization failed");
     output_file
-        .write(s.as_bytes())
+        .write_all(s.as_bytes())
         .expect("Failed to write to file");
 
     // for d in digraphs.iter() {
@@ -0,0 +1,297 @@
+use crate::{Digraph, Frequency, Letter, NGram, NGramSampler, Trigraph};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// The probability given to letters and transitions that a model has never seen.
+const UNSEEN_PROBABILITY: f32 = 1e-6;
+
+/// The most distinct letters a text may use for its trigraphs to be smoothed. Smoothing fills in every possible
+/// trigraph, so the table grows with the cube of the alphabet: 32 letters make 32,768 trigraphs.
+const MAX_SMOOTHED_TRIGRAPH_ALPHABET: usize = 32;
+
+/// Frequency tables gathered from a body of text, in the same form as the crate's n-gram assets.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Analysis {
+    /// The frequency of each letter.
+    pub letters: Vec<Letter>,
+    /// The frequency of each pair of letters within a word.
+    pub digraphs: Vec<Digraph>,
+    /// The frequency of each run of three letters within a word.
+    #[serde(default)]
+    pub trigraphs: Vec<Trigraph>,
+    /// The frequency of each word length, where index `i` holds words of `i + 1` characters.
+    pub word_lengths: Vec<f32>,
+    /// The frequency of each letter as the first letter of a word.
+    #[serde(default)]
+    pub initials: Vec<Letter>,
+    /// The frequency of each letter as the last letter of a word.
+    #[serde(default)]
+    pub finals: Vec<Letter>,
+}
+
+/// Analyzes the given text, counting letters, digraphs, trigraphs, word lengths and the letters words start and end
+/// with. Words are runs of alphabetic characters, and are compared in lower case.
+pub fn analyze(text: &str) -> Analysis {
+    analyze_smoothed(text, 0.0)
+}
+
+/// Analyzes the given text like [`analyze`], but with add-k smoothing: `k` is added to the count of every possible
+/// n-gram of the letters seen in the text, so small corpora don't leave transitions with zero probability. Word
+/// lengths are not smoothed, and neither are trigraphs when the text uses more than 32 distinct letters, as there would
+/// be too many to fill in. Panics if `k` is negative.
+pub fn analyze_smoothed(text: &str, k: f32) -> Analysis {
+    assert!(k >= 0.0, "Smoothing must not be negative, got {}", k);
+    let mut letters = BTreeMap::<[char; 1], usize>::new();
+    let mut digraphs = BTreeMap::<[char; 2], usize>::new();
+    let mut trigraphs = BTreeMap::<[char; 3], usize>::new();
+    let mut word_lengths = Vec::<usize>::new();
+    let mut initials = BTreeMap::<[char; 1], usize>::new();
+    let mut finals = BTreeMap::<[char; 1], usize>::new();
+
+    for word in words(text) {
+        let chars: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+        for c in chars.iter() {
+            *letters.entry([*c]).or_default() += 1;
+        }
+        for pair in chars.windows(2) {
+            *digraphs.entry([pair[0], pair[1]]).or_default() += 1;
+        }
+        for triple in chars.windows(3) {
+            *trigraphs
+                .entry([triple[0], triple[1], triple[2]])
+                .or_default() += 1;
+        }
+        *initials.entry([chars[0]]).or_default() += 1;
+        *finals.entry([chars[chars.len() - 1]]).or_default() += 1;
+        if word_lengths.len() < chars.len() {
+            word_lengths.resize(chars.len(), 0);
+        }
+        word_lengths[chars.len() - 1] += 1;
+    }
+
+    if k > 0.0 && !letters.is_empty() {
+        let alphabet: Vec<char> = letters.keys().map(|l| l[0]).collect();
+        fill(&mut initials, &alphabet);
+        fill(&mut finals, &alphabet);
+        fill(&mut digraphs, &alphabet);
+        if alphabet.len() <= MAX_SMOOTHED_TRIGRAPH_ALPHABET {
+            fill(&mut trigraphs, &alphabet);
+        }
+    }
+
+    let total: usize = word_lengths.iter().sum();
+    Analysis {
+        letters: frequencies(letters, k),
+        digraphs: frequencies(digraphs, k),
+        trigraphs: frequencies(trigraphs, k),
+        word_lengths: word_lengths
+            .into_iter()
+            .map(|count| count as f32 / total as f32)
+            .collect(),
+        initials: frequencies(initials, k),
+        finals: frequencies(finals, k),
+    }
+}
+
+/// Splits text into its words.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+}
+
+/// Adds an empty count for every n-gram that can be made from the alphabet.
+fn fill<const N: usize>(counts: &mut BTreeMap<[char; N], usize>, alphabet: &[char]) {
+    let mut chars = [alphabet[0]; N];
+    let mut digits = [0; N];
+    loop {
+        counts.entry(chars).or_default();
+        // Count through every combination, like an odometer.
+        let Some(i) = (0..N).rev().find(|i| digits[*i] + 1 < alphabet.len()) else {
+            return;
+        };
+        digits[i] += 1;
+        chars[i] = alphabet[digits[i]];
+        digits[i + 1..].fill(0);
+        chars[i + 1..].fill(alphabet[0]);
+    }
+}
+
+/// Converts a table of counts into n-grams with their relative frequencies, after adding `k` to every count.
+fn frequencies<const N: usize>(counts: BTreeMap<[char; N], usize>, k: f32) -> Vec<NGram<N>> {
+    let total = counts.values().sum::<usize>() as f32 + k * counts.len() as f32;
+    counts
+        .into_iter()
+        .map(|(chars, count)| NGram::new(chars, (count as f32 + k) / total))
+        .collect()
+}
+
+/// Scores words against a letter and digraph model.
+#[derive(Debug, Clone)]
+pub struct Scorer {
+    letters: HashMap<char, f32>,
+    transitions: HashMap<[char; 2], f32>,
+}
+
+impl Scorer {
+    /// Builds a new scorer from the given letter and digraph samplers.
+    pub fn new(letters: &NGramSampler<Letter>, digraphs: &NGramSampler<Digraph>) -> Self {
+        // N-grams with a frequency of zero are left out and scored as unseen, so no total below can be zero.
+        let letters: Vec<&Letter> = letters
+            .sample_set()
+            .into_iter()
+            .filter(|l| l.frequency() > 0.0)
+            .collect();
+        let letter_total: f32 = letters.iter().map(|l| l.frequency()).sum();
+        let letters = letters
+            .into_iter()
+            .map(|l| (l.chars[0], l.frequency() / letter_total))
+            .collect();
+
+        // Digraph frequencies are turned into the probability of the second letter following the first.
+        let digraphs: Vec<&Digraph> = digraphs
+            .sample_set()
+            .into_iter()
+            .filter(|d| d.frequency() > 0.0)
+            .collect();
+        let mut following = HashMap::<char, f32>::new();
+        for d in &digraphs {
+            *following.entry(d.chars[0]).or_default() += d.frequency();
+        }
+        let transitions = digraphs
+            .into_iter()
+            .map(|d| (d.chars, d.frequency() / following[&d.chars[0]]))
+            .collect();
+
+        Self {
+            letters,
+            transitions,
+        }
+    }
+
+    /// Returns the average log-probability of the word's first letter and each following transition. Scores closer
+    /// to zero fit the model better; `(-score).exp()` gives the word's perplexity.
+    pub fn score(&self, word: &str) -> f32 {
+        let chars: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+        let Some(first) = chars.first() else {
+            return 0.0;
+        };
+        let probability = |p: Option<&f32>| p.copied().unwrap_or(UNSEEN_PROBABILITY).ln();
+        let total = probability(self.letters.get(first))
+            + chars
+                .windows(2)
+                .map(|pair| probability(self.transitions.get(pair)))
+                .sum::<f32>();
+        total / chars.len() as f32
+    }
+}
+
+/// Picks which of the given models most likely produced the word, returning its index and a confidence between
+/// 0 and 1. Returns `None` if no models are given.
+pub fn classify(word: &str, models: &[Scorer]) -> Option<(usize, f32)> {
+    let len = word.chars().count().max(1) as f32;
+    let likelihoods: Vec<f32> = models.iter().map(|m| m.score(word) * len).collect();
+    let (best, max) = likelihoods
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))?;
+    // Softmax over the total log-likelihoods, shifted by the maximum to keep the exponents in range.
+    let total: f32 = likelihoods.iter().map(|l| (l - max).exp()).sum();
+    Some((best, 1.0 / total))
+}
+
+impl Default for Scorer {
+    fn default() -> Self {
+        Self::new(&Default::default(), &Default::default())
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Frequency;
+
+    #[test]
+    fn analyze_test() {
+        let analysis = analyze("The cat sat, the CAT ran!");
+        assert_eq!(analysis.letters.len(), 8);
+        let t = analysis.letters.iter().find(|l| l.chars == ['t']).unwrap();
+        assert_eq!(t.frequency(), 5.0 / 18.0);
+
+        let at = analysis
+            .digraphs
+            .iter()
+            .find(|d| d.chars == ['a', 't'])
+            .unwrap();
+        assert_eq!(at.frequency(), 3.0 / 12.0);
+
+        assert_eq!(analysis.trigraphs.len(), 4);
+        assert_eq!(analysis.word_lengths, vec![0.0, 0.0, 1.0]);
+        assert_eq!(analysis.initials.len(), 4);
+        let n = analysis.finals.iter().find(|l| l.chars == ['n']).unwrap();
+        assert_eq!(n.frequency(), 1.0 / 6.0);
+        assert_eq!(analyze("").word_lengths, Vec::<f32>::new());
+    }
+
+    #[test]
+    fn smoothing_test() {
+        let analysis = analyze_smoothed("abc", 1.0);
+        assert_eq!(analysis.digraphs.len(), 9);
+        assert_eq!(analysis.trigraphs.len(), 27);
+        let ab = analysis
+            .digraphs
+            .iter()
+            .find(|d| d.chars == ['a', 'b'])
+            .unwrap();
+        let ba = analysis
+            .digraphs
+            .iter()
+            .find(|d| d.chars == ['b', 'a'])
+            .unwrap();
+        assert_eq!(ab.frequency(), 2.0 / 11.0);
+        assert_eq!(ba.frequency(), 1.0 / 11.0);
+
+        assert_eq!(analyze_smoothed("abc", 0.0).digraphs.len(), 2);
+        let wide: String = ('a'..='z').chain('α'..='ω').collect();
+        let analysis = analyze_smoothed(&wide, 1.0);
+        assert_eq!(analysis.digraphs.len(), 51 * 51);
+        assert_eq!(analysis.trigraphs.len(), 49);
+        assert_eq!(analyze_smoothed("", 1.0).digraphs.len(), 0);
+    }
+
+    #[test]
+    fn score_test() {
+        let scorer = Scorer::default();
+        assert!(scorer.score("Thornton") > scorer.score("Qxzvjq"));
+        assert!(scorer.score("there") > scorer.score("xqert"));
+        assert_eq!(scorer.score(""), 0.0);
+
+        // A letter whose digraphs all have zero frequency mustn't divide by zero.
+        let scorer = Scorer::new(
+            &NGramSampler::new(vec![Letter::new(['a'], 1.0), Letter::new(['b'], 0.0)]),
+            &NGramSampler::new(vec![
+                Digraph::new(['a', 'b'], 1.0),
+                Digraph::new(['b', 'a'], 0.0),
+            ]),
+        );
+        assert!(scorer.score("aba").is_finite());
+        assert_eq!(scorer.score("ba"), UNSEEN_PROBABILITY.ln());
+    }
+
+    #[test]
+    fn classify_test() {
+        let norse =
+            analyze("Ragnar Sigurd Bjorn Ivar Ulf Gunnar Halfdan Thorvald Eirik Sven Torstein");
+        let norse = Scorer::new(
+            &NGramSampler::new(norse.letters),
+            &NGramSampler::new(norse.digraphs),
+        );
+        let models = [Scorer::default(), norse];
+
+        let (best, confidence) = classify("Gunnbjorn", &models).unwrap();
+        assert_eq!(best, 1);
+        assert!(confidence > 0.5 && confidence <= 1.0);
+        assert_eq!(classify("Gunnbjorn", &[]), None);
+    }
+}
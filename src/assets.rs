@@ -0,0 +1,374 @@
+use crate::{Error, Frequency, NGram};
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "fs")]
+use std::{fs, path::Path};
+
+/// The current version of the n-gram asset schema.
+pub const NGRAM_SCHEMA_VERSION: u32 = 2;
+
+/// Limits enforced when loading n-gram tables from untrusted sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The largest input, in bytes, that will be parsed.
+    pub max_bytes: usize,
+    /// The most n-grams a table may contain.
+    pub max_ngrams: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 1 << 20,
+            max_ngrams: 1 << 16,
+        }
+    }
+}
+
+/// Descriptive information about where an n-gram table came from, so trained models can be shared.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Metadata {
+    /// A name for the model.
+    pub name: Option<String>,
+    /// The corpus the model was trained on.
+    pub source: Option<String>,
+    /// When the model was trained, in whatever format the author prefers, such as an ISO 8601 date.
+    pub trained: Option<String>,
+}
+
+/// A versioned table of n-grams, as stored in RON assets.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NGramTable<const N: usize> {
+    /// The schema version this table was written with.
+    pub version: u32,
+    /// The n-grams contained by this table.
+    pub ngrams: Vec<NGram<N>>,
+    /// Information about the table's origin. Added in version 2.
+    #[serde(default)]
+    pub metadata: Metadata,
+}
+
+/// A version 1 table, which the binary format can't read with the current layout.
+#[cfg(feature = "binary")]
+#[derive(Deserialize)]
+struct LegacyTable<const N: usize> {
+    version: u32,
+    ngrams: Vec<NGram<N>>,
+}
+
+impl<const N: usize> NGramTable<N> {
+    /// Builds a new table at the current schema version.
+    pub fn new(ngrams: Vec<NGram<N>>) -> Self {
+        Self {
+            version: NGRAM_SCHEMA_VERSION,
+            ngrams,
+            metadata: Metadata::default(),
+        }
+    }
+
+    /// Returns the table with the given metadata attached.
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Returns the number of characters in each of the table's n-grams.
+    pub fn order(&self) -> usize {
+        N
+    }
+
+    /// Parses a table from RON, upgrading files written with older schema versions. Returns [`Error::InvalidModel`]
+    /// if the table was written by a newer version of the crate. No limits are applied, so use
+    /// [`NGramTable::from_ron_limited`] for untrusted input.
+    pub fn from_ron(s: &str) -> Result<Self, Error> {
+        let table = match ron::from_str::<Self>(s) {
+            Ok(table) => table,
+            // Version 0 assets are a bare list of n-grams with no header.
+            Err(e) => match ron::from_str::<Vec<NGram<N>>>(s) {
+                Ok(ngrams) => Self {
+                    version: 0,
+                    ngrams,
+                    metadata: Metadata::default(),
+                },
+                Err(_) => return Err(e.code.into()),
+            },
+        };
+        table.migrate()
+    }
+
+    /// Loads a table from the RON file at the given path.
+    #[cfg(feature = "fs")]
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_ron(&fs::read_to_string(path)?)
+    }
+
+    /// Parses a table from RON like [`NGramTable::from_ron`], but rejects input that exceeds the given limits, has
+    /// no n-grams, or contains frequencies that can't be sampled. A table that passes can always be converted into
+    /// a sampler. Tables that break the limits give [`Error::InvalidModel`].
+    pub fn from_ron_limited(s: &str, limits: &Limits) -> Result<Self, Error> {
+        check_size(s.len(), limits)?;
+        Self::from_ron(s)?.check(limits)
+    }
+
+    /// Parses a table from the compact binary format like [`NGramTable::from_bytes`], but checks it against the
+    /// given limits like [`NGramTable::from_ron_limited`].
+    #[cfg(feature = "binary")]
+    pub fn from_bytes_limited(bytes: &[u8], limits: &Limits) -> Result<Self, Error> {
+        check_size(bytes.len(), limits)?;
+        Self::from_bytes(bytes)?.check(limits)
+    }
+
+    /// Rejects a parsed table that has too many or no n-grams, or frequencies a sampler can't use.
+    fn check(self, limits: &Limits) -> Result<Self, Error> {
+        if self.ngrams.is_empty() {
+            return Err(Error::InvalidModel("N-gram table is empty".to_string()));
+        }
+        if self.ngrams.len() > limits.max_ngrams {
+            return Err(Error::InvalidModel(format!(
+                "N-gram table has {} entries, over the limit of {}",
+                self.ngrams.len(),
+                limits.max_ngrams
+            )));
+        }
+        if let Some(ngram) = self
+            .ngrams
+            .iter()
+            .find(|n| !n.frequency().is_finite() || n.frequency() < 0.0)
+        {
+            return Err(Error::InvalidModel(format!(
+                "N-gram '{}' has invalid frequency {}",
+                ngram,
+                ngram.frequency()
+            )));
+        }
+        let total: f32 = self.ngrams.iter().map(|n| n.frequency()).sum();
+        if total == 0.0 {
+            return Err(Error::InvalidModel(
+                "N-gram table has no non-zero frequencies".to_string(),
+            ));
+        }
+        if !total.is_finite() {
+            return Err(Error::InvalidModel(
+                "N-gram table's total frequency is not finite".to_string(),
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Serializes the table to pretty RON at the current schema version.
+    pub fn to_ron(&self) -> Result<String, Error> {
+        Ok(to_string_pretty(self, PrettyConfig::new())?)
+    }
+
+    /// Writes the table to the given path as pretty RON, which can be read back with [`NGramTable::from_path`].
+    #[cfg(feature = "fs")]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        fs::write(path, self.to_ron()?)?;
+        Ok(())
+    }
+
+    /// Serializes the table to the compact binary format.
+    #[cfg(feature = "binary")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(postcard::to_allocvec(self)?)
+    }
+
+    /// Parses a table from the compact binary format, upgrading files written with older schema versions. No limits
+    /// are applied, so use [`NGramTable::from_bytes_limited`] for untrusted input.
+    #[cfg(feature = "binary")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let table = match postcard::from_bytes::<Self>(bytes) {
+            Ok(table) => table,
+            // Version 1 tables end before the metadata.
+            Err(e) => match postcard::from_bytes::<LegacyTable<N>>(bytes) {
+                Ok(legacy) if legacy.version < 2 => Self {
+                    version: legacy.version,
+                    ngrams: legacy.ngrams,
+                    metadata: Metadata::default(),
+                },
+                _ => return Err(e.into()),
+            },
+        };
+        table.migrate()
+    }
+
+    /// Upgrades the table, one version at a time, to the current schema version.
+    /// Returns an error if the table was written by a newer version of the crate.
+    fn migrate(mut self) -> Result<Self, Error> {
+        if self.version > NGRAM_SCHEMA_VERSION {
+            return Err(Error::InvalidModel(format!(
+                "Unsupported n-gram schema version {} (latest is {})",
+                self.version, NGRAM_SCHEMA_VERSION
+            )));
+        }
+        if self.version == 0 {
+            // 0 -> 1: the version header was added, the n-grams themselves are unchanged.
+            self.version = 1;
+        }
+        if self.version == 1 {
+            // 1 -> 2: metadata was added, which older tables leave empty.
+            self.version = 2;
+        }
+        Ok(self)
+    }
+}
+
+/// Rejects input larger than the limits allow, before it is parsed.
+fn check_size(len: usize, limits: &Limits) -> Result<(), Error> {
+    if len > limits.max_bytes {
+        return Err(Error::InvalidModel(format!(
+            "N-gram table is {} bytes, over the limit of {}",
+            len, limits.max_bytes
+        )));
+    }
+    Ok(())
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NGramSampler;
+
+    #[test]
+    fn migration_test() {
+        let legacy = "[(chars: ('a'), frequency: 0.5), (chars: ('b'), frequency: 0.5)]";
+        let table = NGramTable::<1>::from_ron(legacy).unwrap();
+        assert_eq!(table.version, NGRAM_SCHEMA_VERSION);
+        assert_eq!(table.ngrams.len(), 2);
+
+        let table = NGramTable::<1>::from_ron(&table.to_ron().unwrap()).unwrap();
+        assert_eq!(table.ngrams[1].chars, ['b']);
+        assert_eq!(table.ngrams[1].frequency(), 0.5);
+
+        #[cfg(feature = "fs")]
+        {
+            let path = format!("{}/src/letters.ron", env!("CARGO_MANIFEST_DIR"));
+            assert_eq!(NGramTable::<1>::from_path(path).unwrap().ngrams.len(), 26);
+            assert!(matches!(
+                NGramTable::<1>::from_path("missing.ron"),
+                Err(Error::Io(_))
+            ));
+        }
+
+        let future = "(version: 99, ngrams: [])";
+        assert!(matches!(
+            NGramTable::<1>::from_ron(future),
+            Err(Error::InvalidModel(_))
+        ));
+        assert!(matches!(
+            NGramTable::<1>::from_ron("(version: 2"),
+            Err(Error::Parse(_))
+        ));
+
+        let v1 = "(version: 1, ngrams: [(chars: ('a'), frequency: 1.0)])";
+        let table = NGramTable::<1>::from_ron(v1).unwrap();
+        assert_eq!(table.version, NGRAM_SCHEMA_VERSION);
+        assert_eq!(table.metadata, Metadata::default());
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
+    fn save_test() {
+        let metadata = Metadata {
+            name: Some("Test".to_string()),
+            source: Some("letters.ron".to_string()),
+            trained: Some("2024-01-01".to_string()),
+        };
+        let table = NGramTable::<1>::from_ron(include_str!("letters.ron"))
+            .unwrap()
+            .with_metadata(metadata.clone());
+        assert_eq!(table.order(), 1);
+
+        let path = std::env::temp_dir().join("engish_save_test.ron");
+        table.save(&path).unwrap();
+        let back = NGramTable::<1>::from_path(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(back.metadata, metadata);
+        assert_eq!(back.ngrams.len(), 26);
+    }
+
+    #[test]
+    fn limits_test() {
+        let s = "[(chars: ('a'), frequency: 0.5), (chars: ('b'), frequency: 0.5)]";
+        assert!(NGramTable::<1>::from_ron_limited(s, &Limits::default()).is_ok());
+
+        let tight = Limits {
+            max_bytes: 1 << 20,
+            max_ngrams: 1,
+        };
+        assert!(matches!(
+            NGramTable::<1>::from_ron_limited(s, &tight),
+            Err(Error::InvalidModel(_))
+        ));
+        let tight = Limits {
+            max_bytes: 8,
+            max_ngrams: 1 << 16,
+        };
+        assert!(NGramTable::<1>::from_ron_limited(s, &tight).is_err());
+
+        let negative = "[(chars: ('a'), frequency: -1.0)]";
+        assert!(NGramTable::<1>::from_ron_limited(negative, &Limits::default()).is_err());
+        let empty = "(version: 2, ngrams: [])";
+        assert!(NGramTable::<1>::from_ron_limited(empty, &Limits::default()).is_err());
+        let zero = "[(chars: ('a'), frequency: 0.0)]";
+        assert!(NGramTable::<1>::from_ron_limited(zero, &Limits::default()).is_err());
+        let huge = "[(chars: ('a'), frequency: 3e38), (chars: ('b'), frequency: 3e38)]";
+        assert!(matches!(
+            NGramTable::<1>::from_ron_limited(huge, &Limits::default()),
+            Err(Error::InvalidModel(_))
+        ));
+        let huge = NGramTable::<1>::from_ron(huge).unwrap();
+        assert!(matches!(
+            NGramSampler::try_from(huge),
+            Err(Error::InvalidModel(_))
+        ));
+
+        let table = NGramTable::<1>::from_ron_limited(s, &Limits::default()).unwrap();
+        assert_eq!(NGramSampler::try_from(table).unwrap().len(), 2);
+        let empty = NGramTable::<1>::from_ron(empty).unwrap();
+        assert!(matches!(
+            NGramSampler::try_from(empty),
+            Err(Error::EmptySampler)
+        ));
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn binary_test() {
+        let s = include_str!("digraphs.ron");
+        let table = NGramTable::<2>::from_ron(s).unwrap();
+        let bytes = table.to_bytes().unwrap();
+        assert!(bytes.len() < s.len() / 4);
+
+        let back = NGramTable::<2>::from_bytes(&bytes).unwrap();
+        assert_eq!(back.ngrams.len(), table.ngrams.len());
+        assert!(NGramTable::<2>::from_bytes_limited(&bytes, &Limits::default()).is_ok());
+        let tight = Limits {
+            max_bytes: 16,
+            max_ngrams: 1 << 16,
+        };
+        assert!(matches!(
+            NGramTable::<2>::from_bytes_limited(&bytes, &tight),
+            Err(Error::InvalidModel(_))
+        ));
+        let empty = NGramTable::<2>::new(Vec::new()).to_bytes().unwrap();
+        assert!(NGramTable::<2>::from_bytes_limited(&empty, &Limits::default()).is_err());
+        assert_eq!(back.ngrams[0].chars, table.ngrams[0].chars);
+        assert_eq!(back.ngrams[0].frequency(), table.ngrams[0].frequency());
+
+        #[derive(Serialize)]
+        struct V1<'a> {
+            version: u32,
+            ngrams: &'a [NGram<2>],
+        }
+        let v1 = postcard::to_allocvec(&V1 {
+            version: 1,
+            ngrams: &table.ngrams,
+        })
+        .unwrap();
+        let back = NGramTable::<2>::from_bytes(&v1).unwrap();
+        assert_eq!(back.version, NGRAM_SCHEMA_VERSION);
+        assert_eq!(back.ngrams.len(), table.ngrams.len());
+    }
+}
@@ -0,0 +1,72 @@
+use std::{error, fmt, io};
+
+/// Errors that can occur while loading or sampling n-gram data.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading a file failed.
+    Io(io::Error),
+    /// A RON asset could not be parsed.
+    Parse(ron::Error),
+    /// A binary asset could not be decoded.
+    #[cfg(feature = "binary")]
+    Binary(postcard::Error),
+    /// A sampler was given no n-grams to choose from.
+    EmptySampler,
+    /// A sampler was asked for vowels but its alphabet contains none.
+    NoVowels,
+    /// A sampler was asked for consonants but its alphabet contains none.
+    NoConsonants,
+    /// The loaded data can't be used to build a model, such as when every frequency is zero.
+    InvalidModel(String),
+    /// A pronunciation or pronouncing dictionary could not be parsed.
+    #[cfg(feature = "phonetics")]
+    InvalidPronunciation(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "IO error: {}", e),
+            Error::Parse(e) => write!(f, "Parse error: {}", e),
+            #[cfg(feature = "binary")]
+            Error::Binary(e) => write!(f, "Binary decoding error: {}", e),
+            Error::EmptySampler => write!(f, "Sampler alphabet is empty"),
+            Error::NoVowels => write!(f, "Sampler has no vowels"),
+            Error::NoConsonants => write!(f, "Sampler has no consonants"),
+            Error::InvalidModel(reason) => write!(f, "Invalid model: {}", reason),
+            #[cfg(feature = "phonetics")]
+            Error::InvalidPronunciation(reason) => write!(f, "Invalid pronunciation: {}", reason),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse(e) => Some(e),
+            #[cfg(feature = "binary")]
+            Error::Binary(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ron::Error> for Error {
+    fn from(e: ron::Error) -> Self {
+        Error::Parse(e)
+    }
+}
+
+#[cfg(feature = "binary")]
+impl From<postcard::Error> for Error {
+    fn from(e: postcard::Error) -> Self {
+        Error::Binary(e)
+    }
+}
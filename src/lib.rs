@@ -3,10 +3,43 @@
 //! Engish is a library that provides a silly interface for sampling letters and words in an English style.
 //! Letter sampling is weighted according to the english language, and support for bigraphs is provided.
 
+/// Emits a `tracing` event when the `trace` feature is enabled, and compiles to nothing otherwise.
+#[allow(unused_macros)]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "trace")]
+        tracing::trace!($($arg)*);
+    };
+}
+
 /// N-gram collections
 mod ngrams;
 pub use ngrams::*;
 
+/// Versioned n-gram asset files.
+mod assets;
+pub use assets::*;
+
+/// The crate's error type.
+mod error;
+pub use error::*;
+
+/// Frequency analysis of text.
+pub mod analysis;
+
+/// Spelling utilities for English words.
+pub mod util;
+
+/// Diceware-style passphrase generation.
+pub mod passphrase;
+
+/// Transliteration of words into other scripts.
+pub mod transliteration;
+
+/// Pronunciations of real words from the CMU Pronouncing Dictionary.
+#[cfg(feature = "phonetics")]
+pub mod phonetics;
+
 /// Support for word generation.
 #[cfg(feature = "words")]
 pub mod words;
@@ -1,55 +1,9 @@
-use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::ThreadRng};
-use ron::de::from_reader;
+use crate::{Error, NGramTable, VOWLES};
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, fs::File};
-use crate::VOWLES;
-
-/// A macro used to quickly construct an n-gram type.
-#[macro_export]
-macro_rules! n_gram(
-    ($T: ident, $n: literal) => {
-        #[derive(Debug, Deserialize, Serialize, Clone)]
-        /// n-gram type.
-        pub struct $T {
-            /// The characters contained by this type.
-            pub chars: [char; $n],
-            frequency: f32,
-        }
-
-        impl Display for $T {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                for c in self.chars.iter() {
-                    write!(f, "{}", c)?;
-                }
-                Ok(())
-            }
-        }
-
-        impl Frequency for $T {
-            fn frequency(&self) -> f32 {
-                self.frequency
-            }
-        }
-
-        impl AlphabetType for $T {
-            fn contains_vowel(&self) -> bool {
-                VOWLES.iter().any(|v| self.chars.contains(v))
-            }
-
-            fn contains_consonant(&self) -> bool {
-                VOWLES.iter().any(|v| !self.chars.contains(v))
-            }
-
-            fn is_consonant(&self) -> bool {
-                self.chars.iter().all(|v| !VOWLES.contains(v))
-            }
-
-            fn is_vowel(&self) -> bool {
-                self.chars.iter().all(|v| VOWLES.contains(v))
-            }
-        }
-    }
-);
+use std::{collections::HashMap, fmt::Display, sync::OnceLock};
+#[cfg(feature = "fs")]
+use std::{fs, path::Path};
 
 /// A trait that annotates anything that can have "frequency".
 pub trait Frequency {
@@ -69,9 +23,108 @@ pub trait AlphabetType {
     fn is_vowel(&self) -> bool;
 }
 
-n_gram!(Letter, 1);
-n_gram!(Digraph, 2);
-n_gram!(Trigraph, 3);
+/// An n-gram of `N` characters, with its frequency in the language.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NGram<const N: usize> {
+    /// The characters contained by this type.
+    #[serde(with = "char_array")]
+    pub chars: [char; N],
+    frequency: f32,
+}
+
+/// A single letter.
+pub type Letter = NGram<1>;
+/// A pair of letters.
+pub type Digraph = NGram<2>;
+/// A group of three letters.
+pub type Trigraph = NGram<3>;
+
+impl<const N: usize> NGram<N> {
+    /// Builds a new n-gram from the given characters and frequency.
+    pub fn new(chars: [char; N], frequency: f32) -> Self {
+        Self { chars, frequency }
+    }
+}
+
+impl<const N: usize> Display for NGram<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for c in self.chars.iter() {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> Frequency for NGram<N> {
+    fn frequency(&self) -> f32 {
+        self.frequency
+    }
+}
+
+impl<const N: usize> AlphabetType for NGram<N> {
+    fn contains_vowel(&self) -> bool {
+        VOWLES.iter().any(|v| self.chars.contains(v))
+    }
+
+    fn contains_consonant(&self) -> bool {
+        VOWLES.iter().any(|v| !self.chars.contains(v))
+    }
+
+    fn is_consonant(&self) -> bool {
+        self.chars.iter().all(|v| !VOWLES.contains(v))
+    }
+
+    fn is_vowel(&self) -> bool {
+        self.chars.iter().all(|v| VOWLES.contains(v))
+    }
+}
+
+/// Serde only implements arrays up to a fixed length, so n-gram characters are (de)serialized as a tuple by hand.
+mod char_array {
+    use serde::{
+        de::{Error, SeqAccess, Visitor},
+        ser::SerializeTuple,
+        Deserializer, Serializer,
+    };
+    use std::fmt;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        chars: &[char; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for c in chars {
+            tuple.serialize_element(c)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[char; N], D::Error> {
+        deserializer.deserialize_tuple(N, CharArrayVisitor::<N>)
+    }
+
+    struct CharArrayVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for CharArrayVisitor<N> {
+        type Value = [char; N];
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a tuple of {} characters", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut chars = ['\0'; N];
+            for (i, c) in chars.iter_mut().enumerate() {
+                *c = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(i, &self))?;
+            }
+            Ok(chars)
+        }
+    }
+}
 
 impl From<&Letter> for char {
     fn from(letter: &Letter) -> char {
@@ -79,6 +132,9 @@ impl From<&Letter> for char {
     }
 }
 
+/// The n-grams that start with each character, as alphabet indices with their weights.
+type Following = HashMap<char, (Vec<usize>, WeightedIndex<f32>)>;
+
 /// A sampler for n-grams.
 #[derive(Clone, Debug)]
 pub struct NGramSampler<T>
@@ -89,16 +145,40 @@ where
     weights: WeightedIndex<f32>,
     vowels: Vec<usize>,
     consonants: Vec<usize>,
-    vowel_weights: WeightedIndex<f32>,
-    consonant_weights: WeightedIndex<f32>,
+    vowel_weights: Option<WeightedIndex<f32>>,
+    consonant_weights: Option<WeightedIndex<f32>>,
+    temperature: f32,
+    top_k: Option<usize>,
+    top_p: Option<f32>,
+    following: OnceLock<Following>,
 }
 
 impl<T> NGramSampler<T>
 where
     T: Display + Frequency + Clone + AlphabetType,
 {
-    /// Builds a new sampler using the given alphabet.
+    /// Builds a new sampler using the given alphabet. Panics if the alphabet is empty or its frequencies can't be
+    /// sampled; see [`NGramSampler::try_new`] for a fallible version.
     pub fn new(alphabet: Vec<T>) -> Self {
+        Self::try_new(alphabet).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds a new sampler using the given alphabet, returning an error if the alphabet is empty or its
+    /// frequencies are negative, non-finite, all zero or too large to add up.
+    pub fn try_new(alphabet: Vec<T>) -> Result<Self, Error> {
+        if alphabet.is_empty() {
+            return Err(Error::EmptySampler);
+        }
+        if !alphabet
+            .iter()
+            .map(|l| l.frequency())
+            .sum::<f32>()
+            .is_finite()
+        {
+            return Err(Error::InvalidModel(
+                "total frequency is not finite".to_string(),
+            ));
+        }
         let l = alphabet.len();
         let mut vowels = Vec::new();
         let mut consonants = Vec::new();
@@ -116,78 +196,398 @@ where
                 base_vowel_weights.push(l.frequency());
             }
         }
-        let weights = WeightedIndex::new(&base_weights).unwrap();
-        let vowel_weights = WeightedIndex::new(&base_vowel_weights).unwrap();
-        let consonant_weights = WeightedIndex::new(&base_consonant_weights).unwrap();
-        Self {
+        let weights =
+            WeightedIndex::new(&base_weights).map_err(|e| Error::InvalidModel(e.to_string()))?;
+        // Small trained alphabets might lack vowels or consonants entirely.
+        let vowel_weights = WeightedIndex::new(&base_vowel_weights).ok();
+        let consonant_weights = WeightedIndex::new(&base_consonant_weights).ok();
+        Ok(Self {
             alphabet,
             weights,
             vowels,
             consonants,
             consonant_weights,
             vowel_weights,
+            temperature: 1.0,
+            top_k: None,
+            top_p: None,
+            following: OnceLock::new(),
+        })
+    }
+
+    /// Returns the sampler with the given temperature applied. See [`NGramSampler::set_temperature`].
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.set_temperature(temperature);
+        self
+    }
+
+    /// Sets the sampling temperature, rescaling each weight by `w^(1/T)`. Values below 1.0 favour common n-grams,
+    /// values above 1.0 flatten the distribution towards rare ones. Panics if the temperature is not positive.
+    pub fn set_temperature(&mut self, temperature: f32) {
+        assert!(
+            temperature > 0.0 && temperature.is_finite(),
+            "Temperature must be positive, got {}",
+            temperature
+        );
+        self.temperature = temperature;
+        self.reweight();
+    }
+
+    /// Returns the current sampling temperature.
+    pub fn temperature(&self) -> f32 {
+        self.temperature
+    }
+
+    /// Returns the sampler with top-k truncation applied. See [`NGramSampler::set_top_k`].
+    pub fn with_top_k(mut self, k: usize) -> Self {
+        self.set_top_k(Some(k));
+        self
+    }
+
+    /// Restricts sampling to the `k` most frequent candidates, or removes the restriction if `None`.
+    /// Panics if `k` is zero.
+    pub fn set_top_k(&mut self, k: Option<usize>) {
+        assert!(k != Some(0), "Top-k must keep at least one candidate");
+        self.top_k = k;
+        self.reweight();
+    }
+
+    /// Returns the current top-k cutoff, if any.
+    pub fn top_k(&self) -> Option<usize> {
+        self.top_k
+    }
+
+    /// Returns the sampler with nucleus (top-p) truncation applied. See [`NGramSampler::set_top_p`].
+    pub fn with_top_p(mut self, p: f32) -> Self {
+        self.set_top_p(Some(p));
+        self
+    }
+
+    /// Restricts sampling to the smallest set of most frequent candidates whose combined probability reaches `p`,
+    /// or removes the restriction if `None`. Panics if `p` is not within `(0, 1]`.
+    pub fn set_top_p(&mut self, p: Option<f32>) {
+        if let Some(p) = p {
+            assert!(
+                p > 0.0 && p <= 1.0,
+                "Top-p must be within (0, 1], got {}",
+                p
+            );
+        }
+        self.top_p = p;
+        self.reweight();
+    }
+
+    /// Returns the current top-p cutoff, if any.
+    pub fn top_p(&self) -> Option<f32> {
+        self.top_p
+    }
+
+    /// Rebuilds all weighted indices from the alphabet's frequencies.
+    fn reweight(&mut self) {
+        self.weights = self
+            .weighted_index(0..self.alphabet.len())
+            .expect("Sampler alphabet must not be empty");
+        self.vowel_weights = self.weighted_index(self.vowels.iter().copied());
+        self.consonant_weights = self.weighted_index(self.consonants.iter().copied());
+        self.following = OnceLock::new();
+    }
+
+    /// Builds a weighted index over the given alphabet indices, applying the temperature and truncation. Returns
+    /// `None` if there are no indices to weight.
+    fn weighted_index(
+        &self,
+        indices: impl Iterator<Item = usize> + Clone,
+    ) -> Option<WeightedIndex<f32>> {
+        let frequency = |i: usize| self.alphabet[i].frequency();
+        // Normalise against the largest frequency so low temperatures can't underflow every weight to zero.
+        let max = indices.clone().map(frequency).fold(0.0, f32::max);
+        let exponent = 1.0 / self.temperature;
+        let mut weights: Vec<f32> = indices
+            .map(|i| (frequency(i) / max).powf(exponent))
+            .collect();
+        self.truncate(&mut weights);
+        WeightedIndex::new(weights).ok()
+    }
+
+    /// Zeroes every weight that falls outside the top-k and top-p cutoffs. The most frequent candidate is always kept.
+    fn truncate(&self, weights: &mut [f32]) {
+        if self.top_k.is_none() && self.top_p.is_none() {
+            return;
+        }
+        let mut ranked: Vec<usize> = (0..weights.len()).collect();
+        ranked.sort_by(|a, b| weights[*b].total_cmp(&weights[*a]));
+        let total: f32 = weights.iter().sum();
+        let mut covered = 0.0;
+        for (rank, i) in ranked.into_iter().enumerate() {
+            let within_k = self.top_k.is_none_or(|k| rank < k);
+            let within_p = self.top_p.is_none_or(|p| covered < p * total);
+            if within_k && within_p {
+                covered += weights[i];
+            } else {
+                weights[i] = 0.0;
+            }
         }
     }
 
     /// Takes a random value using a weighted frequency.
-    pub fn sample(&self, rng: &mut ThreadRng) -> &T {
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
         &self.alphabet[self.weights.sample(rng)]
     }
 
-    /// Takes a random vowel, using weight frequencies.
-    pub fn sample_vowels(&self, rng: &mut ThreadRng) -> &T {
-        &self.alphabet[self.vowels[self.vowel_weights.sample(rng)]]
+    /// Takes a random vowel, using weight frequencies. Panics if the alphabet has no vowels.
+    pub fn sample_vowels<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        self.try_sample_vowels(rng)
+            .unwrap_or_else(|e| panic!("{}", e))
     }
 
-    /// Takes a random consonant, using weight frequencies.
-    pub fn sample_consonants(&self, rng: &mut ThreadRng) -> &T {
-        &self.alphabet[self.consonants[self.consonant_weights.sample(rng)]]
+    /// Takes a random vowel, using weight frequencies, or returns an error if the alphabet has no vowels.
+    pub fn try_sample_vowels<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<&T, Error> {
+        let weights = self.vowel_weights.as_ref().ok_or(Error::NoVowels)?;
+        Ok(&self.alphabet[self.vowels[weights.sample(rng)]])
+    }
+
+    /// Takes a random consonant, using weight frequencies. Panics if the alphabet has no consonants.
+    pub fn sample_consonants<R: Rng + ?Sized>(&self, rng: &mut R) -> &T {
+        self.try_sample_consonants(rng)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Takes a random consonant, using weight frequencies, or returns an error if the alphabet has no consonants.
+    pub fn try_sample_consonants<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<&T, Error> {
+        let weights = self.consonant_weights.as_ref().ok_or(Error::NoConsonants)?;
+        Ok(&self.alphabet[self.consonants[weights.sample(rng)]])
+    }
+
+    /// Returns an infinite iterator of weighted samples, drawn using the given rng.
+    pub fn sample_iter<'a, R: Rng + ?Sized>(
+        &'a self,
+        rng: &'a mut R,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        std::iter::repeat_with(move || self.sample(rng))
     }
 
     /// Returns a refference to the entire sample set of alphabetical data.
     pub fn sample_set(&self) -> Vec<&T> {
-        return self.alphabet.iter().map(|x| x).collect()
+        self.alphabet.iter().collect()
     }
 
     /// Returns the length of the sample set.
     pub fn len(&self) -> usize {
         self.alphabet.len()
     }
+
+    /// Returns true if the sample set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.alphabet.is_empty()
+    }
 }
 
-impl Default for NGramSampler<Letter> {
-    fn default() -> Self {
-        let input_path = format!("{}/src/letters.ron", env!("CARGO_MANIFEST_DIR"));
-        let f = File::open(&input_path).expect("Failed opening file");
-        let config: Vec<Letter> = match from_reader(f) {
-            Ok(x) => x,
+impl<const N: usize> NGramSampler<NGram<N>> {
+    /// Loads a sampler from the RON n-gram table at the given path.
+    #[cfg(feature = "fs")]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let s = fs::read_to_string(path)?;
+        Self::from_ron(&s)
+    }
+
+    /// Writes the sampler's n-grams to the given path as a RON n-gram table. Use [`NGramSampler::to_table`] and
+    /// [`NGramTable::with_metadata`] to record where the model came from.
+    #[cfg(feature = "fs")]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        self.to_table().save(path)
+    }
+
+    /// Returns the sampler's n-grams as a table at the current schema version.
+    pub fn to_table(&self) -> NGramTable<N> {
+        NGramTable::new(self.alphabet.clone())
+    }
+
+    /// Builds a sampler from a RON n-gram table. See [`NGramTable::from_ron`].
+    pub fn from_ron(s: &str) -> Result<Self, Error> {
+        Self::try_new(NGramTable::from_ron(s)?.ngrams)
+    }
+
+    /// Takes a random n-gram that starts with the given characters, using weight frequencies. Returns `None` if no
+    /// n-gram in the alphabet starts with them. This is the conditional probability of the following characters, so a
+    /// trigraph sampler given the last two letters of a word suggests the next one.
+    pub fn sample_given<R: Rng + ?Sized>(&self, prefix: &[char], rng: &mut R) -> Option<&NGram<N>> {
+        match prefix {
+            [] => Some(self.sample(rng)),
+            [c] => self.sample_following(*c, rng),
+            [c, ..] => {
+                let candidates: Vec<usize> = self
+                    .following()
+                    .get(c)?
+                    .0
+                    .iter()
+                    .copied()
+                    .filter(|i| self.alphabet[*i].chars.starts_with(prefix))
+                    .collect();
+                let weights = self.weighted_index(candidates.iter().copied())?;
+                Some(&self.alphabet[candidates[weights.sample(rng)]])
+            }
+        }
+    }
+
+    /// Takes a random n-gram that starts with `prev`, using weight frequencies. Returns `None` if no n-gram in the
+    /// alphabet starts with it. Unlike [`NGramSampler::sample_given`] with longer prefixes, this never allocates once
+    /// the sampler has built its index of starting characters.
+    pub fn sample_following<R: Rng + ?Sized>(&self, prev: char, rng: &mut R) -> Option<&NGram<N>> {
+        let (indices, weights) = self.following().get(&prev)?;
+        Some(&self.alphabet[indices[weights.sample(rng)]])
+    }
+
+    /// Returns the index of n-grams by their starting character, building it on first use.
+    fn following(&self) -> &Following {
+        self.following.get_or_init(|| {
+            let mut groups = HashMap::<char, Vec<usize>>::new();
+            for (i, ngram) in self.alphabet.iter().enumerate() {
+                if let Some(c) = ngram.chars.first() {
+                    groups.entry(*c).or_default().push(i);
+                }
+            }
+            groups
+                .into_iter()
+                .filter_map(|(c, indices)| {
+                    let weights = self.weighted_index(indices.iter().copied())?;
+                    Some((c, (indices, weights)))
+                })
+                .collect()
+        })
+    }
+
+    /// Interpolates between this sampler and another, where `t = 0.0` gives this sampler's frequencies and `t = 1.0`
+    /// gives the other's. Frequencies are normalised first, so models trained on different amounts of text blend
+    /// evenly. N-grams missing from either sampler count as having zero frequency. The result keeps this sampler's
+    /// temperature and truncation settings. Panics if `t` is not within `[0, 1]`.
+    pub fn blend(&self, other: &Self, t: f32) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&t),
+            "Blend factor must be within [0, 1], got {}",
+            t
+        );
+        let total = |s: &Self| s.alphabet.iter().map(|n| n.frequency()).sum::<f32>();
+        let (self_total, other_total) = (total(self), total(other));
+
+        let mut ngrams: Vec<NGram<N>> = self
+            .alphabet
+            .iter()
+            .map(|n| NGram::new(n.chars, n.frequency() / self_total * (1.0 - t)))
+            .collect();
+        let mut index: HashMap<[char; N], usize> = ngrams
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.chars, i))
+            .collect();
+        for n in &other.alphabet {
+            let frequency = n.frequency() / other_total * t;
+            match index.get(&n.chars) {
+                Some(&i) => ngrams[i].frequency += frequency,
+                None => {
+                    index.insert(n.chars, ngrams.len());
+                    ngrams.push(NGram::new(n.chars, frequency));
+                }
+            }
+        }
+
+        let mut sampler = Self::new(ngrams);
+        sampler.temperature = self.temperature;
+        sampler.top_k = self.top_k;
+        sampler.top_p = self.top_p;
+        sampler.reweight();
+        sampler
+    }
+
+    /// Builds a sampler from one of the crate's embedded RON assets.
+    fn load_asset(s: &str) -> Self {
+        match Self::from_ron(s) {
+            Ok(sampler) => sampler,
             Err(e) => {
                 panic!("Failed to load config: {}", e);
             }
-        };
-        NGramSampler::new(config)
+        }
     }
 }
 
-impl Default for NGramSampler<Digraph> {
+/// The serialized form of a sampler. Weighted indices are rebuilt from the alphabet on load.
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "NGramSampler")]
+struct SamplerState<A> {
+    alphabet: A,
+    temperature: f32,
+    top_k: Option<usize>,
+    top_p: Option<f32>,
+}
+
+impl<T> Serialize for NGramSampler<T>
+where
+    T: Display + Frequency + Clone + Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SamplerState {
+            alphabet: &self.alphabet,
+            temperature: self.temperature,
+            top_k: self.top_k,
+            top_p: self.top_p,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for NGramSampler<T>
+where
+    T: Display + Frequency + Clone + AlphabetType + Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = SamplerState::<Vec<T>>::deserialize(deserializer)?;
+        if !(state.temperature > 0.0 && state.temperature.is_finite())
+            || state.top_k == Some(0)
+            || state.top_p.is_some_and(|p| !(p > 0.0 && p <= 1.0))
+        {
+            return Err(serde::de::Error::custom("invalid sampler parameters"));
+        }
+        let mut sampler =
+            NGramSampler::try_new(state.alphabet).map_err(serde::de::Error::custom)?;
+        sampler.temperature = state.temperature;
+        sampler.top_k = state.top_k;
+        sampler.top_p = state.top_p;
+        sampler.reweight();
+        Ok(sampler)
+    }
+}
+
+impl<const N: usize> TryFrom<NGramTable<N>> for NGramSampler<NGram<N>> {
+    type Error = Error;
+
+    /// Builds a sampler from the table's n-grams. See [`NGramSampler::try_new`].
+    fn try_from(table: NGramTable<N>) -> Result<Self, Error> {
+        NGramSampler::try_new(table.ngrams)
+    }
+}
+
+impl Default for NGramSampler<Letter> {
     fn default() -> Self {
-        let input_path = format!("{}/src/digraphs.ron", env!("CARGO_MANIFEST_DIR"));
-        let f = File::open(&input_path).expect("Failed opening file");
-        let config: Vec<Digraph> = match from_reader(f) {
-            Ok(x) => x,
-            Err(e) => {
-                panic!("Failed to load config: {}", e);
-            }
-        };
-        NGramSampler::new(config)
+        static LETTERS: OnceLock<NGramSampler<Letter>> = OnceLock::new();
+        LETTERS
+            .get_or_init(|| Self::load_asset(include_str!("letters.ron")))
+            .clone()
     }
 }
 
+impl Default for NGramSampler<Digraph> {
+    fn default() -> Self {
+        static DIGRAPHS: OnceLock<NGramSampler<Digraph>> = OnceLock::new();
+        DIGRAPHS
+            .get_or_init(|| Self::load_asset(include_str!("digraphs.ron")))
+            .clone()
+    }
+}
 
 // Tests
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::mock::StepRng;
 
     #[test]
     fn letter_test() {
@@ -212,4 +612,168 @@ mod tests {
             assert!(!s.is_vowel());
         }
     }
+
+    #[test]
+    fn sample_iter_test() {
+        let sampler = NGramSampler::<Letter>::default();
+        let mut rng = rand::thread_rng();
+
+        let word: String = sampler
+            .sample_iter(&mut rng)
+            .take(10)
+            .map(char::from)
+            .collect();
+        assert_eq!(word.len(), 10);
+    }
+
+    #[test]
+    fn mock_rng_test() {
+        let sampler = NGramSampler::<Letter>::default();
+        let mut rng = StepRng::new(0, 0);
+
+        // A zeroed rng always lands on the first weighted entry.
+        let word: String = sampler
+            .sample_iter(&mut rng)
+            .take(3)
+            .map(char::from)
+            .collect();
+        assert_eq!(word, "aaa");
+        assert_eq!(char::from(sampler.sample_consonants(&mut rng)), 'b');
+    }
+
+    #[test]
+    fn temperature_test() {
+        let sampler = NGramSampler::<Letter>::default().with_temperature(0.01);
+        let mut rng = rand::thread_rng();
+
+        // At a near-zero temperature only the most common letter should ever be picked.
+        for _ in 0..100 {
+            assert_eq!(char::from(sampler.sample(&mut rng)), 'e');
+            assert_eq!(char::from(sampler.sample_consonants(&mut rng)), 't');
+        }
+    }
+
+    #[test]
+    fn truncation_test() {
+        let mut rng = rand::thread_rng();
+
+        let sampler = NGramSampler::<Letter>::default().with_top_k(3);
+        for _ in 0..100 {
+            assert!(['e', 't', 'a'].contains(&sampler.sample(&mut rng).into()));
+        }
+
+        // 'e' alone covers more than 10% of the mass, so nothing else survives.
+        let sampler = NGramSampler::<Letter>::default().with_top_p(0.1);
+        for _ in 0..100 {
+            assert_eq!(char::from(sampler.sample(&mut rng)), 'e');
+        }
+    }
+
+    #[test]
+    fn sampler_serde_test() {
+        let sampler = NGramSampler::<Letter>::default()
+            .with_temperature(0.5)
+            .with_top_k(3);
+        let s = ron::to_string(&sampler).unwrap();
+        let back: NGramSampler<Letter> = ron::from_str(&s).unwrap();
+        assert_eq!(back.len(), sampler.len());
+        assert_eq!(back.temperature(), 0.5);
+        assert_eq!(back.top_k(), Some(3));
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            assert!(['e', 't', 'a'].contains(&back.sample(&mut rng).into()));
+        }
+    }
+
+    #[test]
+    fn following_test() {
+        let mut rng = rand::thread_rng();
+        let sampler = NGramSampler::<Digraph>::default();
+        for _ in 0..100 {
+            assert_eq!(
+                sampler.sample_following('q', &mut rng).unwrap().chars[0],
+                'q'
+            );
+        }
+        assert!(sampler.sample_following('?', &mut rng).is_none());
+
+        let sampler = sampler.with_top_k(1);
+        let first = sampler.sample_following('t', &mut rng).unwrap().chars;
+        assert_eq!(
+            sampler.sample_following('t', &mut rng).unwrap().chars,
+            first
+        );
+    }
+
+    #[test]
+    fn blend_test() {
+        let a = NGramSampler::new(vec![Letter::new(['a'], 2.0), Letter::new(['b'], 2.0)]);
+        let b = NGramSampler::new(vec![Letter::new(['b'], 1.0), Letter::new(['c'], 3.0)]);
+
+        let frequency = |s: &NGramSampler<Letter>, c: char| {
+            s.sample_set()
+                .iter()
+                .find(|l| l.chars[0] == c)
+                .map_or(0.0, |l| l.frequency())
+        };
+        let start = a.blend(&b, 0.0);
+        assert_eq!(frequency(&start, 'a'), 0.5);
+        assert_eq!(frequency(&start, 'c'), 0.0);
+
+        let middle = a.with_temperature(0.5).blend(&b, 0.5);
+        assert_eq!(middle.len(), 3);
+        assert_eq!(middle.temperature(), 0.5);
+        assert_eq!(frequency(&middle, 'a'), 0.25);
+        assert_eq!(frequency(&middle, 'b'), 0.375);
+        assert_eq!(frequency(&middle, 'c'), 0.375);
+    }
+
+    #[test]
+    fn error_test() {
+        let mut rng = rand::thread_rng();
+        assert!(matches!(
+            NGramSampler::<Letter>::try_new(Vec::new()),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            NGramSampler::try_new(vec![Letter::new(['a'], 0.0)]),
+            Err(Error::InvalidModel(_))
+        ));
+
+        let vowels = NGramSampler::new(vec![Letter::new(['a'], 1.0), Letter::new(['e'], 1.0)]);
+        assert!(vowels.try_sample_vowels(&mut rng).is_ok());
+        assert!(matches!(
+            vowels.try_sample_consonants(&mut rng),
+            Err(Error::NoConsonants)
+        ));
+
+        #[cfg(feature = "fs")]
+        {
+            let path = format!("{}/src/letters.ron", env!("CARGO_MANIFEST_DIR"));
+            assert_eq!(NGramSampler::<Letter>::load(path).unwrap().len(), 26);
+            assert!(matches!(
+                NGramSampler::<Letter>::load("missing.ron"),
+                Err(Error::Io(_))
+            ));
+        }
+        assert!(matches!(
+            NGramSampler::<Letter>::from_ron("[("),
+            Err(Error::Parse(_))
+        ));
+        assert!(ron::from_str::<NGramSampler<Letter>>(
+            "(alphabet: [], temperature: 1.0, top_k: None, top_p: None)"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn ngram_serde_test() {
+        let quadgraph = NGram::new(['t', 'i', 'o', 'n'], 0.01);
+        let s = ron::to_string(&quadgraph).unwrap();
+        let back: NGram<4> = ron::from_str(&s).unwrap();
+        assert_eq!(back.chars, quadgraph.chars);
+        assert_eq!(back.frequency(), quadgraph.frequency());
+        assert!(ron::from_str::<NGram<4>>("(chars: ('a', 'b'), frequency: 0.1)").is_err());
+    }
 }
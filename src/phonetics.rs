@@ -0,0 +1,526 @@
+use crate::Error;
+use std::{collections::HashMap, fmt, str::FromStr};
+#[cfg(feature = "fs")]
+use std::{fs, path::Path};
+
+/// The ARPAbet vowels, which carry a stress marker in CMUdict transcriptions.
+const VOWELS: [&str; 15] = [
+    "AA", "AE", "AH", "AO", "AW", "AY", "EH", "ER", "EY", "IH", "IY", "OW", "OY", "UH", "UW",
+];
+
+/// The ARPAbet consonants.
+const CONSONANTS: [&str; 24] = [
+    "B", "CH", "D", "DH", "F", "G", "HH", "JH", "K", "L", "M", "N", "NG", "P", "R", "S", "SH", "T",
+    "TH", "V", "W", "Y", "Z", "ZH",
+];
+
+/// How strongly a syllable is stressed, as marked on CMUdict vowels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stress {
+    /// No stress, marked `0`.
+    Unstressed,
+    /// Primary stress, marked `1`.
+    Primary,
+    /// Secondary stress, marked `2`.
+    Secondary,
+}
+
+/// A single ARPAbet phoneme, such as `K` or `AE1`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Phoneme {
+    symbol: String,
+    stress: Option<Stress>,
+}
+
+impl Phoneme {
+    /// Returns the phoneme's symbol without its stress marker.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the stress of a vowel, or `None` for consonants.
+    pub fn stress(&self) -> Option<Stress> {
+        self.stress
+    }
+
+    /// Returns true if the phoneme is a vowel, and so the nucleus of a syllable.
+    pub fn is_vowel(&self) -> bool {
+        VOWELS.contains(&self.symbol.as_str())
+    }
+}
+
+impl FromStr for Phoneme {
+    type Err = Error;
+
+    /// Parses an ARPAbet symbol. Vowels may end with a stress marker from 0 to 2; vowels without one are unstressed.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let invalid = || Error::InvalidPronunciation(format!("unknown phoneme '{}'", s));
+        let (symbol, stress) = match s.strip_suffix(['0', '1', '2']) {
+            Some(symbol) => {
+                let stress = match &s[symbol.len()..] {
+                    "0" => Stress::Unstressed,
+                    "1" => Stress::Primary,
+                    _ => Stress::Secondary,
+                };
+                (symbol, Some(stress))
+            }
+            None => (s, None),
+        };
+        if VOWELS.contains(&symbol) {
+            Ok(Self {
+                symbol: symbol.to_string(),
+                stress: Some(stress.unwrap_or(Stress::Unstressed)),
+            })
+        } else if CONSONANTS.contains(&symbol) && stress.is_none() {
+            Ok(Self {
+                symbol: symbol.to_string(),
+                stress: None,
+            })
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+impl fmt::Display for Phoneme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.stress {
+            Some(Stress::Unstressed) => write!(f, "{}0", self.symbol),
+            Some(Stress::Primary) => write!(f, "{}1", self.symbol),
+            Some(Stress::Secondary) => write!(f, "{}2", self.symbol),
+            None => write!(f, "{}", self.symbol),
+        }
+    }
+}
+
+/// A word's pronunciation as a sequence of ARPAbet phonemes, such as `K AE1 T` for "cat".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pronunciation {
+    phonemes: Vec<Phoneme>,
+}
+
+impl Pronunciation {
+    /// Returns the phonemes in order.
+    pub fn phonemes(&self) -> &[Phoneme] {
+        &self.phonemes
+    }
+
+    /// Returns the number of syllables, which is the number of vowels.
+    pub fn syllable_count(&self) -> usize {
+        self.phonemes.iter().filter(|p| p.is_vowel()).count()
+    }
+
+    /// Returns the stress of each syllable in order.
+    pub fn stress(&self) -> Vec<Stress> {
+        self.phonemes.iter().filter_map(|p| p.stress).collect()
+    }
+
+    /// Returns the phonemes from the last stressed vowel onwards, which two words must share to rhyme. Falls back to
+    /// the last vowel if none are stressed, and the whole pronunciation if there are no vowels.
+    pub fn rhyme(&self) -> &[Phoneme] {
+        let stressed = |p: &Phoneme| matches!(p.stress, Some(Stress::Primary | Stress::Secondary));
+        let start = self
+            .phonemes
+            .iter()
+            .rposition(stressed)
+            .or_else(|| self.phonemes.iter().rposition(Phoneme::is_vowel))
+            .unwrap_or(0);
+        &self.phonemes[start..]
+    }
+}
+
+impl FromStr for Pronunciation {
+    type Err = Error;
+
+    /// Parses whitespace-separated ARPAbet phonemes.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let phonemes = s
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<Phoneme>, Error>>()?;
+        if phonemes.is_empty() {
+            return Err(Error::InvalidPronunciation(
+                "pronunciation has no phonemes".to_string(),
+            ));
+        }
+        Ok(Self { phonemes })
+    }
+}
+
+impl fmt::Display for Pronunciation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, phoneme) in self.phonemes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", phoneme)?;
+        }
+        Ok(())
+    }
+}
+
+/// Pronunciations of real words, read from the CMU Pronouncing Dictionary format. Words are looked up
+/// case-insensitively, and may have several pronunciations ("read" as `R IY1 D` or `R EH1 D`).
+#[derive(Debug, Clone, Default)]
+pub struct PhoneticDictionary {
+    words: HashMap<String, Vec<Pronunciation>>,
+}
+
+impl PhoneticDictionary {
+    /// Builds a new, empty dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a dictionary in the CMUdict format: one word per line followed by its phonemes, with alternative
+    /// pronunciations written as `WORD(2)`. Lines starting with `;;;` and anything after a `#` are comments.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut dictionary = Self::new();
+        for (i, line) in s.lines().enumerate() {
+            if line.starts_with(";;;") {
+                continue;
+            }
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((word, phonemes)) = line.split_once(char::is_whitespace) else {
+                if line.is_empty() {
+                    continue;
+                }
+                return Err(Error::InvalidPronunciation(format!(
+                    "line {} has no phonemes",
+                    i + 1
+                )));
+            };
+            // Strip the variant number from alternative pronunciations.
+            let word = match word.split_once('(') {
+                Some((word, variant)) if variant.ends_with(')') => word,
+                _ => word,
+            };
+            let pronunciation = phonemes.parse().map_err(|e| match e {
+                Error::InvalidPronunciation(reason) => {
+                    Error::InvalidPronunciation(format!("{} on line {}", reason, i + 1))
+                }
+                e => e,
+            })?;
+            dictionary.insert(word, pronunciation);
+        }
+        Ok(dictionary)
+    }
+
+    /// Loads a CMUdict-format dictionary from the given path.
+    #[cfg(feature = "fs")]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let s = fs::read_to_string(path)?;
+        Self::parse(&s)
+    }
+
+    /// Adds a pronunciation for the word, after any it already has.
+    pub fn insert(&mut self, word: &str, pronunciation: Pronunciation) {
+        self.words
+            .entry(word.to_lowercase())
+            .or_default()
+            .push(pronunciation);
+    }
+
+    /// Returns the word's most common pronunciation.
+    pub fn get(&self, word: &str) -> Option<&Pronunciation> {
+        self.pronunciations(word).first()
+    }
+
+    /// Returns every pronunciation of the word, most common first.
+    pub fn pronunciations(&self, word: &str) -> &[Pronunciation] {
+        self.words
+            .get(&word.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the number of syllables in the word's most common pronunciation.
+    pub fn syllable_count(&self, word: &str) -> Option<usize> {
+        self.get(word).map(Pronunciation::syllable_count)
+    }
+
+    /// Returns the stress of each syllable in the word's most common pronunciation.
+    pub fn stress(&self, word: &str) -> Option<Vec<Stress>> {
+        self.get(word).map(Pronunciation::stress)
+    }
+
+    /// Returns the word's most common pronunciation, or a guess from its spelling if it isn't in the dictionary. See
+    /// [`transcribe`].
+    pub fn pronounce(&self, word: &str) -> Option<Pronunciation> {
+        self.get(word).cloned().or_else(|| transcribe(word))
+    }
+
+    /// Returns true if any pronunciations of two different words rhyme.
+    pub fn rhymes(&self, a: &str, b: &str) -> bool {
+        if a.eq_ignore_ascii_case(b) {
+            return false;
+        }
+        let b = self.pronunciations(b);
+        self.pronunciations(a)
+            .iter()
+            .any(|a| b.iter().any(|b| a.rhyme() == b.rhyme()))
+    }
+
+    /// Returns an iterator over every word in the dictionary, in no particular order.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.words.keys().map(String::as_str)
+    }
+
+    /// Returns the number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns true if the dictionary has no words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+/// Spellings that don't make a single sound per letter, longest first, with the phonemes they make. Patterns ending
+/// in 'r' only apply when the 'r' isn't followed by a vowel, so "ar" in "card" is `AA R` but "arena" keeps its short
+/// 'a'.
+const GRAPHEMES: [(&str, &[&str]); 37] = [
+    ("eigh", &["EY"]),
+    ("tch", &["CH"]),
+    ("igh", &["AY"]),
+    ("dg", &["JH"]),
+    ("ch", &["CH"]),
+    ("sh", &["SH"]),
+    ("th", &["TH"]),
+    ("ph", &["F"]),
+    ("wh", &["W"]),
+    ("ck", &["K"]),
+    ("ng", &["NG"]),
+    ("qu", &["K", "W"]),
+    ("gh", &[]),
+    ("ai", &["EY"]),
+    ("ay", &["EY"]),
+    ("ey", &["EY"]),
+    ("ee", &["IY"]),
+    ("ea", &["IY"]),
+    ("ie", &["IY"]),
+    ("oa", &["OW"]),
+    ("oe", &["OW"]),
+    ("oo", &["UW"]),
+    ("ou", &["AW"]),
+    ("ow", &["OW"]),
+    ("oi", &["OY"]),
+    ("oy", &["OY"]),
+    ("au", &["AO"]),
+    ("aw", &["AO"]),
+    ("ew", &["UW"]),
+    ("ue", &["UW"]),
+    ("ar", &["AA", "R"]),
+    ("er", &["ER"]),
+    ("ir", &["ER"]),
+    ("ur", &["ER"]),
+    ("yr", &["ER"]),
+    ("or", &["AO", "R"]),
+    ("x", &["K", "S"]),
+];
+
+/// Guesses the pronunciation of a word from its spelling, for generated words that aren't in any dictionary. Common
+/// spellings are mapped to their usual sounds, a final silent 'e' lengthens the vowel before it ("kite"), and the
+/// first syllable is stressed with later short vowels reduced to a schwa, as in most English names. Non-ASCII
+/// letters are ignored. Returns `None` if the word has no ASCII letters, as a pronunciation needs at least one
+/// phoneme.
+pub fn transcribe(word: &str) -> Option<Pronunciation> {
+    let word: String = word
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let chars = word.as_bytes();
+    let n = chars.len();
+    // A 'y' is a vowel unless it starts a word or is followed by another vowel.
+    let is_vowel = |i: usize| match chars.get(i) {
+        Some(b'a' | b'e' | b'i' | b'o' | b'u') => true,
+        Some(b'y') => i > 0 && !matches!(chars.get(i + 1), Some(b'a' | b'e' | b'i' | b'o' | b'u')),
+        _ => false,
+    };
+    let silent_e = n > 2 && chars[n - 1] == b'e' && !is_vowel(n - 2) && (0..n - 2).any(is_vowel);
+    let magic_e = silent_e && is_vowel(n - 3) && (n == 3 || !is_vowel(n - 4));
+
+    let mut symbols = Vec::<&str>::new();
+    let mut i = 0;
+    while i < n {
+        let rest = &word[i..];
+        let c = chars[i];
+        if silent_e && i == n - 1 {
+            break;
+        }
+        if magic_e && i == n - 3 {
+            symbols.push(match c {
+                b'a' => "EY",
+                b'e' => "IY",
+                b'i' | b'y' => "AY",
+                b'o' => "OW",
+                _ => "UW",
+            });
+            i += 1;
+            continue;
+        }
+        if i == 0 && (rest.starts_with("kn") || rest.starts_with("wr")) {
+            i += 1;
+            continue;
+        }
+        let grapheme = GRAPHEMES.iter().find(|(spelling, _)| {
+            rest.starts_with(spelling) && !(spelling.ends_with('r') && is_vowel(i + spelling.len()))
+        });
+        if let Some((spelling, sounds)) = grapheme {
+            symbols.extend_from_slice(sounds);
+            i += spelling.len();
+            continue;
+        }
+
+        let next = chars.get(i + 1).copied();
+        let softened = matches!(next, Some(b'e' | b'i' | b'y'));
+        symbols.push(match c {
+            b'a' => "AE",
+            b'e' => "EH",
+            b'i' => "IH",
+            b'o' => "AA",
+            b'u' => "AH",
+            b'y' if !is_vowel(i) => "Y",
+            // A final 'y' is a long 'i' in words without other vowels ("fly"), and a long 'e' otherwise ("happy").
+            b'y' if i == n - 1 && (0..i).any(is_vowel) => "IY",
+            b'y' if i == n - 1 => "AY",
+            b'y' => "IH",
+            b'c' if softened => "S",
+            b'g' if softened => "JH",
+            b'c' | b'k' | b'q' => "K",
+            b'g' => "G",
+            b'j' => "JH",
+            b'h' => "HH",
+            b'b' => "B",
+            b'd' => "D",
+            b'f' => "F",
+            b'l' => "L",
+            b'm' => "M",
+            b'n' => "N",
+            b'p' => "P",
+            b'r' => "R",
+            b's' => "S",
+            b't' => "T",
+            b'v' => "V",
+            b'w' => "W",
+            _ => "Z",
+        });
+        // Double consonants make a single sound.
+        i += if !is_vowel(i) && next == Some(c) {
+            2
+        } else {
+            1
+        };
+    }
+
+    let mut stressed = false;
+    let phonemes = symbols
+        .into_iter()
+        .map(|symbol| {
+            if !VOWELS.contains(&symbol) {
+                return Phoneme {
+                    symbol: symbol.to_string(),
+                    stress: None,
+                };
+            }
+            let (symbol, stress) = match (stressed, symbol) {
+                (false, _) => (symbol, Stress::Primary),
+                (true, "AE" | "AA" | "EH") => ("AH", Stress::Unstressed),
+                (true, _) => (symbol, Stress::Unstressed),
+            };
+            stressed = true;
+            Phoneme {
+                symbol: symbol.to_string(),
+                stress: Some(stress),
+            }
+        })
+        .collect::<Vec<_>>();
+    (!phonemes.is_empty()).then_some(Pronunciation { phonemes })
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CMUDICT: &str = ";;; A few words from the CMU Pronouncing Dictionary
+CAT  K AE1 T
+HAT  HH AE1 T
+NIGHT  N AY1 T
+KITE  K AY1 T
+READ  R IY1 D
+READ(2)  R EH1 D # past tense
+RED  R EH1 D
+BANANA  B AH0 N AE1 N AH0
+";
+
+    #[test]
+    fn parse_test() {
+        let dictionary = PhoneticDictionary::parse(CMUDICT).unwrap();
+        assert_eq!(dictionary.len(), 7);
+        assert_eq!(dictionary.get("Cat").unwrap().to_string(), "K AE1 T");
+        assert_eq!(dictionary.pronunciations("read").len(), 2);
+        assert_eq!(dictionary.syllable_count("banana"), Some(3));
+        assert_eq!(
+            dictionary.stress("banana"),
+            Some(vec![
+                Stress::Unstressed,
+                Stress::Primary,
+                Stress::Unstressed
+            ])
+        );
+        assert_eq!(dictionary.get("dog"), None);
+
+        assert!(dictionary.rhymes("night", "kite"));
+        assert!(dictionary.rhymes("read", "red"));
+        assert!(!dictionary.rhymes("cat", "kite"));
+        assert!(!dictionary.rhymes("cat", "cat"));
+        assert_eq!(dictionary.pronounce("read").unwrap().to_string(), "R IY1 D");
+        assert_eq!(
+            dictionary.pronounce("Kaylin").unwrap().to_string(),
+            "K EY1 L IH0 N"
+        );
+        assert_eq!(dictionary.pronounce("42"), None);
+    }
+
+    #[test]
+    fn transcribe_test() {
+        let cases = [
+            ("cat", "K AE1 T"),
+            ("night", "N AY1 T"),
+            ("kite", "K AY1 T"),
+            ("Phone", "F OW1 N"),
+            ("banana", "B AE1 N AH0 N AH0"),
+            ("knight", "N AY1 T"),
+            ("happy", "HH AE1 P IY0"),
+            ("fly", "F L AY1"),
+            ("Cedric", "S EH1 D R IH0 K"),
+            ("Yardley", "Y AA1 R D L EY0"),
+        ];
+        for (word, pronunciation) in cases {
+            let transcribed = transcribe(word).unwrap();
+            assert_eq!(transcribed.to_string(), pronunciation, "{}", word);
+            // Every transcription can be read back.
+            assert_eq!(pronunciation.parse::<Pronunciation>().unwrap(), transcribed);
+        }
+        assert_eq!(
+            transcribe("night").unwrap().rhyme(),
+            transcribe("kite").unwrap().rhyme()
+        );
+        assert_eq!(transcribe(""), None);
+        assert_eq!(transcribe("Æ"), None);
+        assert!("".parse::<Pronunciation>().is_err());
+    }
+
+    #[test]
+    fn invalid_test() {
+        assert!(PhoneticDictionary::parse("CAT  K AE1 Q").is_err());
+        assert!(PhoneticDictionary::parse("CAT").is_err());
+        assert!("T1".parse::<Phoneme>().is_err());
+        assert_eq!(
+            "AE".parse::<Phoneme>().unwrap().stress(),
+            Some(Stress::Unstressed)
+        );
+    }
+}
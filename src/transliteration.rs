@@ -0,0 +1,156 @@
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+
+/// The Elder Futhark, as (Latin, rune) pairs. Digraphs with a rune of their own are listed alongside single letters.
+const FUTHARK: [(&str, &str); 30] = [
+    ("a", "ᚨ"),
+    ("b", "ᛒ"),
+    ("c", "ᚲ"),
+    ("d", "ᛞ"),
+    ("e", "ᛖ"),
+    ("f", "ᚠ"),
+    ("g", "ᚷ"),
+    ("h", "ᚺ"),
+    ("i", "ᛁ"),
+    ("j", "ᛃ"),
+    ("k", "ᚲ"),
+    ("l", "ᛚ"),
+    ("m", "ᛗ"),
+    ("n", "ᚾ"),
+    ("o", "ᛟ"),
+    ("p", "ᛈ"),
+    ("q", "ᚲ"),
+    ("r", "ᚱ"),
+    ("s", "ᛊ"),
+    ("t", "ᛏ"),
+    ("u", "ᚢ"),
+    ("v", "ᚹ"),
+    ("w", "ᚹ"),
+    ("x", "ᚲᛊ"),
+    ("y", "ᛃ"),
+    ("z", "ᛉ"),
+    ("th", "ᚦ"),
+    ("ng", "ᛜ"),
+    ("ei", "ᛇ"),
+    ("qu", "ᚲᚹ"),
+];
+
+/// A table mapping Latin letter sequences to another script. The longest matching sequence is always used, and
+/// anything without a mapping is passed through in lower case.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(try_from = "Mappings")]
+pub struct Script {
+    mappings: Vec<(String, String)>,
+}
+
+/// The serialized form of a script, checked before it is used.
+#[derive(Deserialize)]
+struct Mappings {
+    mappings: Vec<(String, String)>,
+}
+
+impl TryFrom<Mappings> for Script {
+    type Error = Error;
+
+    fn try_from(data: Mappings) -> Result<Self, Error> {
+        let mut script = Self::new();
+        for (from, to) in data.mappings {
+            if from.is_empty() {
+                return Err(Error::InvalidModel(format!(
+                    "script maps an empty sequence to '{}'",
+                    to
+                )));
+            }
+            script.add_mapping(&from, &to);
+        }
+        Ok(script)
+    }
+}
+
+impl Script {
+    /// Builds a new, empty script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The Elder Futhark runic alphabet.
+    pub fn futhark() -> Self {
+        FUTHARK.iter().fold(Self::new(), |script, (from, to)| {
+            script.with_mapping(from, to)
+        })
+    }
+
+    /// Returns the script with the given mapping added. See [`Script::add_mapping`].
+    pub fn with_mapping(mut self, from: &str, to: &str) -> Self {
+        self.add_mapping(from, to);
+        self
+    }
+
+    /// Maps the given (case-insensitive) Latin sequence to `to`, replacing any existing mapping for it. An empty
+    /// sequence would match everywhere without consuming any text, so it is ignored.
+    pub fn add_mapping(&mut self, from: &str, to: &str) {
+        if from.is_empty() {
+            return;
+        }
+        let from = from.to_lowercase();
+        self.mappings.retain(|(f, _)| *f != from);
+        self.mappings.push((from, to.to_string()));
+        // Keep the longest sequences first so they take priority when matching.
+        self.mappings
+            .sort_by_key(|(from, _)| Reverse(from.chars().count()));
+    }
+
+    /// Transliterates the given text into this script.
+    pub fn transliterate(&self, text: &str) -> String {
+        let text = text.to_lowercase();
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        while let Some(c) = rest.chars().next() {
+            match self
+                .mappings
+                .iter()
+                .find(|(from, _)| rest.starts_with(from.as_str()))
+            {
+                Some((from, to)) => {
+                    output.push_str(to);
+                    rest = &rest[from.len()..];
+                }
+                None => {
+                    output.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        output
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn futhark_test() {
+        let futhark = Script::futhark();
+        assert_eq!(futhark.transliterate("Thing"), "ᚦᛁᛜ");
+        assert_eq!(futhark.transliterate("Odin's hall"), "ᛟᛞᛁᚾ'ᛊ ᚺᚨᛚᛚ");
+    }
+
+    #[test]
+    fn custom_script_test() {
+        let script = Script::new().with_mapping("a", "4").with_mapping("ee", "3");
+        assert_eq!(script.transliterate("Beean"), "b34n");
+
+        let script = script.with_mapping("", "x");
+        assert_eq!(script.transliterate("a"), "4");
+    }
+
+    #[test]
+    fn deserialize_test() {
+        let script: Script = ron::from_str(r#"(mappings: [("a", "4"), ("ee", "3")])"#).unwrap();
+        assert_eq!(script.transliterate("Beean"), "b34n");
+        assert!(ron::from_str::<Script>(r#"(mappings: [("", "x")])"#).is_err());
+    }
+}
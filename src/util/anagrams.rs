@@ -0,0 +1,34 @@
+/// Returns the letters of a word in lower case and sorted order, which is the same for every anagram of it: "Listen"
+/// and "silent" both give "eilnst". Useful as a map key for grouping a word list into anagrams. Non-alphabetic
+/// characters are ignored.
+pub fn anagram_key(word: &str) -> String {
+    let mut letters: Vec<char> = word
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_lowercase)
+        .collect();
+    letters.sort_unstable();
+    letters.into_iter().collect()
+}
+
+/// Returns true if two different words use exactly the same letters, ignoring case, spaces and punctuation.
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    let key = anagram_key(a);
+    !key.is_empty() && key == anagram_key(b) && !a.eq_ignore_ascii_case(b)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anagram_test() {
+        assert_eq!(anagram_key("Listen"), "eilnst");
+        assert!(is_anagram("listen", "Silent"));
+        assert!(is_anagram("Dormitory", "dirty room"));
+        assert!(!is_anagram("listen", "listens"));
+        assert!(!is_anagram("Listen", "listen"));
+        assert!(!is_anagram("", ""));
+    }
+}
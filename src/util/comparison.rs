@@ -0,0 +1,88 @@
+use super::{doubles_final_consonant, match_case, syllabify};
+
+/// Adjectives with irregular comparative and superlative forms.
+const IRREGULAR_COMPARISONS: [(&str, &str, &str); 7] = [
+    ("good", "better", "best"),
+    ("well", "better", "best"),
+    ("bad", "worse", "worst"),
+    ("far", "farther", "farthest"),
+    ("little", "less", "least"),
+    ("many", "more", "most"),
+    ("much", "more", "most"),
+];
+
+/// Two-syllable endings that take "-er" and "-est" rather than "more" and "most".
+const INFLECTED_ENDINGS: [&str; 4] = ["y", "le", "er", "ow"];
+
+/// Returns the comparative form of an English adjective: "bigger", "happier", "more beautiful". Short adjectives
+/// take "-er", while those of three or more syllables, and most of two, use "more". The casing of the input is kept.
+pub fn comparative(adjective: &str) -> String {
+    compare(adjective, 1, "er", "more")
+}
+
+/// Returns the superlative form of an English adjective: "biggest", "happiest", "most beautiful". See
+/// [`comparative`].
+pub fn superlative(adjective: &str) -> String {
+    compare(adjective, 2, "est", "most")
+}
+
+/// Inflects the adjective with the given suffix, or puts the given adverb before it if it's too long.
+fn compare(adjective: &str, form: usize, suffix: &str, adverb: &str) -> String {
+    if adjective.is_empty() {
+        return String::new();
+    }
+    let word = adjective.to_lowercase();
+    if let Some(forms) = IRREGULAR_COMPARISONS.iter().find(|f| f.0 == word) {
+        let irregular = if form == 1 { forms.1 } else { forms.2 };
+        return match_case(adjective, irregular.to_string());
+    }
+
+    let syllables = syllabify(&word).len();
+    let inflected =
+        syllables <= 1 || syllables == 2 && INFLECTED_ENDINGS.iter().any(|e| word.ends_with(e));
+    if !inflected {
+        return format!("{} {}", adverb, adjective);
+    }
+    match_case(adjective, inflect(&word, suffix))
+}
+
+/// Adds "-er" or "-est" to a lower-case adjective, following the spelling rules.
+fn inflect(word: &str, suffix: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let n = chars.len();
+    let is_vowel = |c: char| "aeiou".contains(c);
+    if word.ends_with('e') {
+        return format!("{}{}", word, &suffix[1..]);
+    }
+    if let Some(stem) = word.strip_suffix('y') {
+        if n > 1 && !is_vowel(chars[n - 2]) {
+            return format!("{}i{}", stem, suffix);
+        }
+    }
+    if doubles_final_consonant(word) {
+        return format!("{}{}{}", word, chars[n - 1], suffix);
+    }
+    format!("{}{}", word, suffix)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparison_test() {
+        assert_eq!(comparative("big"), "bigger");
+        assert_eq!(superlative("big"), "biggest");
+        assert_eq!(comparative("large"), "larger");
+        assert_eq!(comparative("happy"), "happier");
+        assert_eq!(superlative("Happy"), "Happiest");
+        assert_eq!(comparative("clever"), "cleverer");
+        assert_eq!(comparative("simple"), "simpler");
+        assert_eq!(comparative("grey"), "greyer");
+        assert_eq!(comparative("beautiful"), "more beautiful");
+        assert_eq!(superlative("famous"), "most famous");
+        assert_eq!(comparative("good"), "better");
+        assert_eq!(superlative("bad"), "worst");
+    }
+}
@@ -0,0 +1,81 @@
+use crate::analysis::Scorer;
+use crate::VOWLES;
+use std::sync::OnceLock;
+
+/// Joins two words into a compound: "fire" + "brand" -> "firebrand". A hyphen is added where joining them would
+/// run three of the same letter together ("bell-lamp").
+pub fn compound(first: &str, second: &str) -> String {
+    let mut tail = first.chars().rev();
+    let tripled = match (tail.next(), tail.next(), second.chars().next()) {
+        (Some(a), Some(b), Some(c)) => a.eq_ignore_ascii_case(&b) && a.eq_ignore_ascii_case(&c),
+        _ => false,
+    };
+    if tripled {
+        format!("{}-{}", first, second)
+    } else {
+        format!("{}{}", first, second.to_lowercase())
+    }
+}
+
+/// Blends the start of one word into the end of another: "breakfast" + "lunch" -> "brunch". Every split that keeps
+/// at least two letters of each word is scored for how English it looks, with bonuses for joining the first word's
+/// opening consonants to the second word from its first vowel ("sm" + "og" -> "smog"), and for splitting both words
+/// at a letter they share ("motor" + "hotel" -> "motel"). Returns `None` if either word is too short to split.
+pub fn portmanteau(first: &str, second: &str) -> Option<String> {
+    static SCORER: OnceLock<Scorer> = OnceLock::new();
+    let scorer = SCORER.get_or_init(Scorer::default);
+
+    let a: Vec<char> = first.to_lowercase().chars().collect();
+    let b: Vec<char> = second.to_lowercase().chars().collect();
+    let target = (a.len() + b.len()) as f32 / 2.0;
+    let first_vowel = |word: &[char]| word.iter().position(|c| VOWLES.contains(c));
+    let onset = (first_vowel(&a), first_vowel(&b));
+    let mut best: Option<(f32, String)> = None;
+    for i in 2..a.len() {
+        for j in 1..b.len().saturating_sub(1) {
+            let blend: String = a[..i].iter().chain(b[j..].iter()).collect();
+            if blend == first.to_lowercase() || blend == second.to_lowercase() {
+                continue;
+            }
+            let shared = if a[i] == b[j] { 1.0 } else { 0.0 };
+            let rime = if onset == (Some(i), Some(j)) {
+                1.0
+            } else {
+                0.0
+            };
+            let length = (blend.chars().count() as f32 - target).abs() * 0.2;
+            let score = scorer.score(&blend) + shared + rime - length;
+            if best.as_ref().is_none_or(|(s, _)| score > *s) {
+                best = Some((score, blend));
+            }
+        }
+    }
+    best.map(|(_, blend)| blend)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compound_test() {
+        assert_eq!(compound("fire", "brand"), "firebrand");
+        assert_eq!(compound("Storm", "Caller"), "Stormcaller");
+        assert_eq!(compound("bell", "lamp"), "bell-lamp");
+    }
+
+    #[test]
+    fn portmanteau_test() {
+        let cases = [
+            ("breakfast", "lunch", "brunch"),
+            ("motor", "hotel", "motel"),
+            ("smoke", "fog", "smog"),
+            ("spoon", "fork", "spork"),
+        ];
+        for (a, b, blend) in cases {
+            assert_eq!(portmanteau(a, b).as_deref(), Some(blend));
+        }
+        assert_eq!(portmanteau("a", "lunch"), None);
+    }
+}
@@ -0,0 +1,241 @@
+use super::{doubles_final_consonant, match_case};
+
+/// Verbs whose agent noun isn't formed with "-er".
+const IRREGULAR_AGENTS: [(&str, &str); 12] = [
+    ("act", "actor"),
+    ("create", "creator"),
+    ("edit", "editor"),
+    ("invent", "inventor"),
+    ("sail", "sailor"),
+    ("visit", "visitor"),
+    ("govern", "governor"),
+    ("collect", "collector"),
+    ("conquer", "conqueror"),
+    ("beg", "beggar"),
+    ("lie", "liar"),
+    ("cook", "cook"),
+];
+
+/// Verbs whose action noun doesn't follow a suffix rule, including common verbs the rules would mangle ("pass" isn't
+/// "passion").
+const IRREGULAR_ACTIONS: [(&str, &str); 36] = [
+    ("destroy", "destruction"),
+    ("decide", "decision"),
+    ("describe", "description"),
+    ("explain", "explanation"),
+    ("fail", "failure"),
+    ("please", "pleasure"),
+    ("grow", "growth"),
+    ("die", "death"),
+    ("choose", "choice"),
+    ("think", "thought"),
+    ("know", "knowledge"),
+    ("arrive", "arrival"),
+    ("refuse", "refusal"),
+    ("deny", "denial"),
+    ("speak", "speech"),
+    ("sell", "sale"),
+    ("lose", "loss"),
+    ("fly", "flight"),
+    ("see", "sight"),
+    ("move", "movement"),
+    ("pay", "payment"),
+    ("achieve", "achievement"),
+    ("enjoy", "enjoyment"),
+    ("agree", "agreement"),
+    ("pass", "passage"),
+    ("kiss", "kiss"),
+    ("miss", "miss"),
+    ("press", "pressure"),
+    ("dress", "dress"),
+    ("rise", "rise"),
+    ("promise", "promise"),
+    ("advise", "advice"),
+    ("hate", "hatred"),
+    ("expect", "expectation"),
+    ("elect", "election"),
+    ("select", "selection"),
+];
+
+/// Suffix rules for action nouns, as pairs of verb endings and their replacements.
+const ACTION_SUFFIXES: [(&str, &str); 9] = [
+    ("ate", "ation"),
+    ("ize", "ization"),
+    ("ise", "isation"),
+    ("ify", "ification"),
+    ("ect", "ection"),
+    ("ict", "iction"),
+    ("uct", "uction"),
+    ("ert", "ertion"),
+    ("ss", "ssion"),
+];
+
+/// The fewest letters a verb must keep before a suffix rule's ending, so short verbs like "hate" and "rise" aren't
+/// treated as "-ate" and "-ise" verbs.
+const MIN_ACTION_STEM: usize = 3;
+
+/// Adjectives whose adverb isn't formed by a rule.
+const IRREGULAR_ADVERBS: [(&str, &str); 8] = [
+    ("good", "well"),
+    ("fast", "fast"),
+    ("hard", "hard"),
+    ("true", "truly"),
+    ("public", "publicly"),
+    ("whole", "wholly"),
+    ("due", "duly"),
+    ("shy", "shyly"),
+];
+
+/// Returns the noun for someone who performs a verb: "bake" -> "baker", "run" -> "runner", "act" -> "actor". The
+/// casing of the input is kept.
+pub fn agent_noun(verb: &str) -> String {
+    derive(verb, &IRREGULAR_AGENTS, |word| with_suffix(word, "er"))
+}
+
+/// Returns the noun for the act of a verb: "create" -> "creation", "destroy" -> "destruction",
+/// "move" -> "movement". Returns `None` for verbs that neither a suffix rule nor the table of irregulars covers
+/// ("bake"), rather than guessing. The casing of the input is kept.
+pub fn action_noun(verb: &str) -> Option<String> {
+    let lower = verb.to_lowercase();
+    let derived = match IRREGULAR_ACTIONS.iter().find(|(w, _)| *w == lower) {
+        Some((_, irregular)) => irregular.to_string(),
+        None => ACTION_SUFFIXES.iter().find_map(|(ending, replacement)| {
+            lower
+                .strip_suffix(ending)
+                .filter(|stem| stem.chars().count() >= MIN_ACTION_STEM)
+                .map(|stem| format!("{}{}", stem, replacement))
+        })?,
+    };
+    Some(match_case(verb, derived))
+}
+
+/// Returns the present participle of a verb, which doubles as an adjective: "burn" -> "burning",
+/// "shine" -> "shining", "lie" -> "lying". The casing of the input is kept.
+pub fn present_participle(verb: &str) -> String {
+    derive(verb, &[], |word| {
+        if let Some(stem) = word.strip_suffix("ie") {
+            return format!("{}ying", stem);
+        }
+        with_suffix(word, "ing")
+    })
+}
+
+/// Returns the past participle of a regular verb, which doubles as an adjective: "curse" -> "cursed",
+/// "enchant" -> "enchanted", "bury" -> "buried". Irregular verbs aren't known, so "break" gives "breaked". The
+/// casing of the input is kept.
+pub fn past_participle(verb: &str) -> String {
+    derive(verb, &[], |word| with_suffix(word, "ed"))
+}
+
+/// Returns the adverb of an adjective: "quick" -> "quickly", "happy" -> "happily", "gentle" -> "gently",
+/// "basic" -> "basically". The casing of the input is kept.
+pub fn adverb_from_adjective(adjective: &str) -> String {
+    derive(adjective, &IRREGULAR_ADVERBS, |word| {
+        if let Some(stem) = word.strip_suffix("le") {
+            if !stem.is_empty() && !stem.ends_with(['a', 'e', 'i', 'o', 'u']) {
+                return format!("{}ly", stem);
+            }
+        }
+        if let Some(stem) = word.strip_suffix('y') {
+            if word.len() > 2 && !stem.ends_with(['a', 'e', 'i', 'o', 'u']) {
+                return format!("{}ily", stem);
+            }
+        }
+        if word.ends_with("ic") {
+            return format!("{}ally", word);
+        }
+        if word.ends_with("ll") {
+            return format!("{}y", word);
+        }
+        format!("{}ly", word)
+    })
+}
+
+/// Looks the word up in a table of irregulars, and otherwise applies the rule to its lower-case form.
+fn derive(word: &str, irregulars: &[(&str, &str)], rule: impl Fn(&str) -> String) -> String {
+    if word.is_empty() {
+        return String::new();
+    }
+    let lower = word.to_lowercase();
+    let derived = match irregulars.iter().find(|(w, _)| *w == lower) {
+        Some((_, irregular)) => irregular.to_string(),
+        None => rule(&lower),
+    };
+    match_case(word, derived)
+}
+
+/// Adds a suffix starting with a vowel, dropping a silent 'e', changing 'y' to 'i' and doubling a final consonant
+/// where the spelling rules call for it.
+fn with_suffix(word: &str, suffix: &str) -> String {
+    let is_vowel = |c: char| "aeiou".contains(c);
+    let chars: Vec<char> = word.chars().collect();
+    let n = chars.len();
+    if let Some(stem) = word.strip_suffix('e') {
+        // "-ing" keeps the 'e' of "ee", "oe" and "ye" ("seeing", "hoeing"), while other suffixes absorb it.
+        let keep =
+            n <= 2 || suffix == "ing" && ["ee", "oe", "ye"].iter().any(|e| word.ends_with(e));
+        if !keep {
+            return format!("{}{}", stem, suffix);
+        }
+        return format!("{}{}", word, suffix);
+    }
+    if let Some(stem) = word.strip_suffix('y') {
+        if suffix != "ing" && n > 1 && !is_vowel(chars[n - 2]) {
+            return format!("{}i{}", stem, suffix);
+        }
+    }
+    if doubles_final_consonant(word) {
+        return format!("{}{}{}", word, chars[n - 1], suffix);
+    }
+    format!("{}{}", word, suffix)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_test() {
+        assert_eq!(agent_noun("bake"), "baker");
+        assert_eq!(agent_noun("run"), "runner");
+        assert_eq!(agent_noun("carry"), "carrier");
+        assert_eq!(agent_noun("play"), "player");
+        assert_eq!(agent_noun("Act"), "Actor");
+
+        assert_eq!(action_noun("create").as_deref(), Some("creation"));
+        assert_eq!(action_noun("Destroy").as_deref(), Some("Destruction"));
+        assert_eq!(action_noun("protect").as_deref(), Some("protection"));
+        assert_eq!(action_noun("discuss").as_deref(), Some("discussion"));
+        assert_eq!(action_noun("pass").as_deref(), Some("passage"));
+        assert_eq!(action_noun("expect").as_deref(), Some("expectation"));
+        assert_eq!(action_noun("hate").as_deref(), Some("hatred"));
+        assert_eq!(action_noun("promise").as_deref(), Some("promise"));
+        assert_eq!(action_noun("rise").as_deref(), Some("rise"));
+        assert_eq!(action_noun("kiss").as_deref(), Some("kiss"));
+        assert_eq!(action_noun("bake"), None);
+        assert_eq!(action_noun("build"), None);
+        assert_eq!(action_noun("mate"), None);
+        assert_eq!(action_noun(""), None);
+
+        assert_eq!(present_participle("shine"), "shining");
+        assert_eq!(present_participle("see"), "seeing");
+        assert_eq!(present_participle("be"), "being");
+        assert_eq!(present_participle("lie"), "lying");
+        assert_eq!(present_participle("carry"), "carrying");
+        assert_eq!(present_participle("stop"), "stopping");
+        assert_eq!(past_participle("curse"), "cursed");
+        assert_eq!(past_participle("bury"), "buried");
+        assert_eq!(past_participle("agree"), "agreed");
+
+        assert_eq!(adverb_from_adjective("quick"), "quickly");
+        assert_eq!(adverb_from_adjective("happy"), "happily");
+        assert_eq!(adverb_from_adjective("gentle"), "gently");
+        assert_eq!(adverb_from_adjective("basic"), "basically");
+        assert_eq!(adverb_from_adjective("full"), "fully");
+        assert_eq!(adverb_from_adjective("good"), "well");
+        assert_eq!(adverb_from_adjective("whole"), "wholly");
+        assert_eq!(adverb_from_adjective("due"), "duly");
+        assert_eq!(adverb_from_adjective("shy"), "shyly");
+    }
+}
@@ -0,0 +1,125 @@
+use super::{pluralize, singularize};
+
+/// Words starting with a vowel letter that are said with a consonant sound, and so take "a".
+const A_BEFORE_VOWEL: [&str; 10] = [
+    "eu", "ewe", "one", "once", "uni", "use", "usu", "uti", "ufo", "uku",
+];
+/// Words starting with a silent 'h', which take "an".
+const AN_BEFORE_H: [&str; 4] = ["hour", "honest", "honou", "heir"];
+
+/// Whether a noun phrase refers to one thing or several.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Number {
+    /// One thing: "this wolf".
+    Singular,
+    /// Several things: "these wolves".
+    Plural,
+}
+
+/// A word that introduces a noun phrase, which must agree with the noun in number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Determiner {
+    /// "the".
+    The,
+    /// "a" or "an", which only takes singular nouns.
+    A,
+    /// "this" or "these".
+    This,
+    /// "that" or "those".
+    That,
+    /// "some".
+    Some,
+    /// "no".
+    No,
+    /// "many", which only takes plural nouns.
+    Many,
+    /// "few", which only takes plural nouns.
+    Few,
+    /// "each", which only takes singular nouns.
+    Each,
+    /// "every", which only takes singular nouns.
+    Every,
+}
+
+impl Determiner {
+    /// Returns the number the determiner requires, or `None` if it works with both.
+    pub fn number(&self) -> Option<Number> {
+        match self {
+            Determiner::A | Determiner::Each | Determiner::Every => Some(Number::Singular),
+            Determiner::Many | Determiner::Few => Some(Number::Plural),
+            _ => None,
+        }
+    }
+
+    /// Builds a noun phrase such as "these wolves", inflecting the noun and the determiner to agree with the given
+    /// number. Determiners that require a number use it instead ("each wolf").
+    pub fn phrase(&self, noun: &str, number: Number) -> String {
+        let number = self.number().unwrap_or(number);
+        let noun = match number {
+            Number::Singular => singularize(noun),
+            Number::Plural => pluralize(noun),
+        };
+        let word = match (self, number) {
+            (Determiner::The, _) => "the",
+            (Determiner::A, _) => indefinite_article(&noun),
+            (Determiner::This, Number::Singular) => "this",
+            (Determiner::This, Number::Plural) => "these",
+            (Determiner::That, Number::Singular) => "that",
+            (Determiner::That, Number::Plural) => "those",
+            (Determiner::Some, _) => "some",
+            (Determiner::No, _) => "no",
+            (Determiner::Many, _) => "many",
+            (Determiner::Few, _) => "few",
+            (Determiner::Each, _) => "each",
+            (Determiner::Every, _) => "every",
+        };
+        format!("{} {}", word, noun)
+    }
+}
+
+/// Returns "a" or "an" for the given word, going by its spelling: "an apple", "a unicorn", "an hour".
+pub fn indefinite_article(word: &str) -> &'static str {
+    let word = word.to_lowercase();
+    let starts_with_vowel = word.starts_with(['a', 'e', 'i', 'o', 'u']);
+    if starts_with_vowel && !A_BEFORE_VOWEL.iter().any(|p| word.starts_with(p))
+        || AN_BEFORE_H.iter().any(|p| word.starts_with(p))
+    {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determiner_test() {
+        assert_eq!(indefinite_article("apple"), "an");
+        assert_eq!(indefinite_article("Unicorn"), "a");
+        assert_eq!(indefinite_article("umbrella"), "an");
+        assert_eq!(indefinite_article("hour"), "an");
+        assert_eq!(indefinite_article("house"), "a");
+
+        assert_eq!(Determiner::A.phrase("owls", Number::Plural), "an owl");
+        assert_eq!(
+            Determiner::This.phrase("wolf", Number::Plural),
+            "these wolves"
+        );
+        assert_eq!(
+            Determiner::That.phrase("wolves", Number::Singular),
+            "that wolf"
+        );
+        assert_eq!(
+            Determiner::Many.phrase("child", Number::Singular),
+            "many children"
+        );
+        assert_eq!(
+            Determiner::Each.phrase("mice", Number::Plural),
+            "each mouse"
+        );
+        assert_eq!(Determiner::The.phrase("sheep", Number::Plural), "the sheep");
+    }
+}
@@ -0,0 +1,189 @@
+/// Returns the Levenshtein distance between two words: the fewest single character insertions, deletions and
+/// substitutions that turn one into the other ("kitten" to "sitting" is 3). Characters are compared exactly, so
+/// case matters.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // Only the previous row of the distance matrix is needed to fill the next.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// A BK-tree of words for finding those within an edit distance of a query without comparing against every one, such
+/// as checking generated names don't land too close to real words. Words are stored and compared in lower case.
+#[derive(Debug, Clone, Default)]
+pub struct WordIndex {
+    nodes: Vec<Node>,
+}
+
+/// A word in the tree, with the indices of its children keyed by their distance from it.
+#[derive(Debug, Clone)]
+struct Node {
+    word: String,
+    children: Vec<(usize, usize)>,
+}
+
+impl WordIndex {
+    /// Builds a new, empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a word to the index. Returns false if it was already there.
+    pub fn insert(&mut self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        if self.nodes.is_empty() {
+            self.nodes.push(Node::new(word));
+            return true;
+        }
+        let mut current = 0;
+        loop {
+            let distance = levenshtein(&word, &self.nodes[current].word);
+            if distance == 0 {
+                return false;
+            }
+            let child = self.nodes[current]
+                .children
+                .iter()
+                .find(|(d, _)| *d == distance);
+            match child {
+                Some((_, child)) => current = *child,
+                None => {
+                    let index = self.nodes.len();
+                    self.nodes[current].children.push((distance, index));
+                    self.nodes.push(Node::new(word));
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Adds all of the given words to the index.
+    pub fn extend<S: AsRef<str>>(&mut self, words: impl IntoIterator<Item = S>) {
+        for word in words {
+            self.insert(word.as_ref());
+        }
+    }
+
+    /// Returns every word within `max_distance` edits of the given word with its distance, closest first.
+    pub fn nearest(&self, word: &str, max_distance: usize) -> Vec<(&str, usize)> {
+        let word = word.to_lowercase();
+        let mut found = Vec::new();
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        while let Some(current) = stack.pop() {
+            let node = &self.nodes[current];
+            let distance = levenshtein(&word, &node.word);
+            if distance <= max_distance {
+                found.push((node.word.as_str(), distance));
+            }
+            // By the triangle inequality, only children this close to the node can hold matches.
+            let range = distance.saturating_sub(max_distance)..=distance + max_distance;
+            stack.extend(
+                node.children
+                    .iter()
+                    .filter(|(d, _)| range.contains(d))
+                    .map(|(_, child)| *child),
+            );
+        }
+        found.sort_by_key(|(_, distance)| *distance);
+        found
+    }
+
+    /// Returns true if the word is in the index, ignoring case.
+    pub fn contains(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        let mut current = (!self.nodes.is_empty()).then_some(0);
+        // An exact match can only lie down the child at the same distance from each node.
+        while let Some(node) = current.map(|i| &self.nodes[i]) {
+            let distance = levenshtein(&word, &node.word);
+            if distance == 0 {
+                return true;
+            }
+            current = node
+                .children
+                .iter()
+                .find(|(d, _)| *d == distance)
+                .map(|(_, child)| *child);
+        }
+        false
+    }
+
+    /// Returns true if any word is within `max_distance` edits of the given word.
+    pub fn contains_near(&self, word: &str, max_distance: usize) -> bool {
+        !self.nearest(word, max_distance).is_empty()
+    }
+
+    /// Returns the number of words in the index.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the index has no words.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl Node {
+    fn new(word: String) -> Self {
+        Self {
+            word,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for WordIndex {
+    fn from_iter<I: IntoIterator<Item = S>>(words: I) -> Self {
+        let mut index = Self::new();
+        index.extend(words);
+        index
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_test() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+        assert_eq!(levenshtein("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn index_test() {
+        let index: WordIndex = ["panic", "shite", "house", "mouse", "horse"]
+            .into_iter()
+            .collect();
+        assert_eq!(index.len(), 5);
+        assert!(index.contains("House"));
+        assert!(!index.contains("hause"));
+        assert_eq!(index.nearest("Penic", 1), vec![("panic", 1)]);
+        assert!(index.contains_near("Shitte", 1));
+        assert!(!index.contains_near("Thornton", 2));
+
+        let near = index.nearest("hause", 1);
+        assert_eq!(near.len(), 1);
+        let near = index.nearest("hause", 2);
+        assert_eq!(near[0], ("house", 1));
+        assert_eq!(near.len(), 3);
+        assert!(WordIndex::new().nearest("any", 3).is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+/// How [`join_list`] joins the items of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListStyle {
+    /// The word placed before the last item, such as "and" or "or".
+    pub conjunction: &'static str,
+    /// Whether a comma also comes before the conjunction in lists of three or more ("a, b, and c").
+    pub oxford_comma: bool,
+}
+
+impl ListStyle {
+    /// Joins with "and", using an Oxford comma.
+    pub const AND: Self = Self {
+        conjunction: "and",
+        oxford_comma: true,
+    };
+    /// Joins with "or", using an Oxford comma.
+    pub const OR: Self = Self {
+        conjunction: "or",
+        oxford_comma: true,
+    };
+
+    /// Returns the style with the Oxford comma turned on or off.
+    pub fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+        self.oxford_comma = oxford_comma;
+        self
+    }
+}
+
+impl Default for ListStyle {
+    fn default() -> Self {
+        Self::AND
+    }
+}
+
+/// Joins items into an English list, as in "a sword, a shield, and a lantern". Two items are joined by the
+/// conjunction alone ("a sword and a shield").
+pub fn join_list<S: AsRef<str>>(items: &[S], style: ListStyle) -> String {
+    match items {
+        [] => String::new(),
+        [only] => only.as_ref().to_string(),
+        [first, second] => format!(
+            "{} {} {}",
+            first.as_ref(),
+            style.conjunction,
+            second.as_ref()
+        ),
+        [rest @ .., last] => {
+            let rest: Vec<&str> = rest.iter().map(|s| s.as_ref()).collect();
+            let comma = if style.oxford_comma { "," } else { "" };
+            format!(
+                "{}{} {} {}",
+                rest.join(", "),
+                comma,
+                style.conjunction,
+                last.as_ref()
+            )
+        }
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_list_test() {
+        let items = ["a sword", "a shield", "a lantern"];
+        assert_eq!(
+            join_list(&items, ListStyle::AND),
+            "a sword, a shield, and a lantern"
+        );
+        assert_eq!(
+            join_list(&items, ListStyle::OR.oxford_comma(false)),
+            "a sword, a shield or a lantern"
+        );
+        assert_eq!(
+            join_list(&items[..2], ListStyle::AND),
+            "a sword and a shield"
+        );
+        assert_eq!(join_list(&items[..1], ListStyle::AND), "a sword");
+        assert_eq!(join_list::<&str>(&[], ListStyle::AND), "");
+    }
+}
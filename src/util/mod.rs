@@ -0,0 +1,65 @@
+mod anagrams;
+pub use anagrams::*;
+mod comparison;
+pub use comparison::*;
+mod compound;
+pub use compound::*;
+mod derive;
+pub use derive::*;
+mod determiners;
+pub use determiners::*;
+mod distance;
+pub use distance::*;
+mod lists;
+pub use lists::*;
+mod numbers;
+pub use numbers::*;
+mod patterns;
+pub use patterns::*;
+mod plural;
+pub use plural::*;
+mod possessive;
+pub use possessive::*;
+mod readable;
+pub use readable::*;
+mod rhymes;
+pub use rhymes::*;
+mod scores;
+pub use scores::*;
+mod soundalike;
+pub use soundalike::*;
+mod syllables;
+pub use syllables::*;
+
+/// Returns true if a lower-case word ends in a single vowel and consonant, which is doubled before a suffix starting
+/// with a vowel: "big" -> "bigger", "run" -> "running". Only single syllable words are doubled, as longer words
+/// depend on their stress ("begin" -> "beginning", but "open" -> "opening").
+fn doubles_final_consonant(word: &str) -> bool {
+    let is_vowel = |c: char| "aeiou".contains(c);
+    let chars: Vec<char> = word.chars().collect();
+    let n = chars.len();
+    n >= 3
+        && !is_vowel(chars[n - 1])
+        && !"wxy".contains(chars[n - 1])
+        && is_vowel(chars[n - 2])
+        && !is_vowel(chars[n - 3])
+        && syllabify(word).len() == 1
+}
+
+/// Returns `word` cased to match `template`: all upper-case, capitalised, or left as it is.
+fn match_case(template: &str, word: String) -> String {
+    let mut letters = template.chars().filter(|c| c.is_alphabetic());
+    let Some(first) = letters.next() else {
+        return word;
+    };
+    if first.is_uppercase() {
+        if template.chars().count() > 1 && letters.all(char::is_uppercase) {
+            return word.to_uppercase();
+        }
+        let mut chars = word.chars();
+        if let Some(c) = chars.next() {
+            return c.to_uppercase().chain(chars).collect();
+        }
+    }
+    word
+}
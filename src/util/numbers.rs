@@ -0,0 +1,169 @@
+use super::{pluralize, singularize};
+
+/// Number words below twenty.
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// Multiples of ten, starting from twenty.
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short scale names for each power of a thousand.
+const SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+/// Whether spelled numbers use "and" before their tens and units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberStyle {
+    /// "one hundred and two".
+    #[default]
+    British,
+    /// "one hundred two".
+    American,
+}
+
+/// Spells out a number in British English words, such as "four hundred and two". See [`spell_number_with`].
+pub fn spell_number(n: i64) -> String {
+    spell_number_with(n, NumberStyle::British)
+}
+
+/// Spells out a number in words, using the short scale ("billion" is a thousand million) up to quintillions.
+/// Negative numbers start with "minus".
+pub fn spell_number_with(n: i64, style: NumberStyle) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut groups = Vec::new();
+    let mut rest = n.unsigned_abs();
+    while rest > 0 {
+        groups.push((rest % 1000) as usize);
+        rest /= 1000;
+    }
+
+    let mut words = Vec::new();
+    if n < 0 {
+        words.push("minus".to_string());
+    }
+    for (scale, group) in groups.iter().enumerate().rev() {
+        if *group == 0 {
+            continue;
+        }
+        // British English joins a trailing number below a hundred with "and": "one thousand and two".
+        if scale == 0 && *group < 100 && groups.len() > 1 && style == NumberStyle::British {
+            words.push("and".to_string());
+        }
+        words.push(spell_hundreds(*group, style));
+        if scale > 0 {
+            words.push(SCALES[scale].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+/// Counts a noun with digits, making the noun agree with the number: "1 wolf", "3 wolves", "no wolves".
+pub fn count_noun(n: i64, noun: &str) -> String {
+    counted(n, noun, n.to_string())
+}
+
+/// Counts a noun with the number spelled out in British English: "one wolf", "three wolves", "no wolves".
+pub fn spell_count_noun(n: i64, noun: &str) -> String {
+    counted(n, noun, spell_number(n))
+}
+
+/// Joins the count to the noun, inflected to agree with it. Zero is written as "no".
+fn counted(n: i64, noun: &str, count: String) -> String {
+    if n.unsigned_abs() == 1 {
+        format!("{} {}", count, singularize(noun))
+    } else if n == 0 {
+        format!("no {}", pluralize(noun))
+    } else {
+        format!("{} {}", count, pluralize(noun))
+    }
+}
+
+/// Spells out a number below a thousand.
+fn spell_hundreds(n: usize, style: NumberStyle) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let mut words = Vec::new();
+    if hundreds > 0 {
+        words.push(format!("{} hundred", ONES[hundreds]));
+        if rest > 0 && style == NumberStyle::British {
+            words.push("and".to_string());
+        }
+    }
+    if rest > 0 {
+        words.push(match rest {
+            0..=19 => ONES[rest].to_string(),
+            _ if rest % 10 == 0 => TENS[rest / 10 - 2].to_string(),
+            _ => format!("{}-{}", TENS[rest / 10 - 2], ONES[rest % 10]),
+        });
+    }
+    words.join(" ")
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_noun_test() {
+        assert_eq!(count_noun(1, "wolves"), "1 wolf");
+        assert_eq!(count_noun(3, "wolf"), "3 wolves");
+        assert_eq!(count_noun(0, "wolf"), "no wolves");
+        assert_eq!(spell_count_noun(3, "child"), "three children");
+        assert_eq!(spell_count_noun(-1, "degree"), "minus one degree");
+    }
+
+    #[test]
+    fn spell_number_test() {
+        assert_eq!(spell_number(0), "zero");
+        assert_eq!(spell_number(13), "thirteen");
+        assert_eq!(spell_number(40), "forty");
+        assert_eq!(spell_number(402), "four hundred and two");
+        assert_eq!(spell_number(-42), "minus forty-two");
+        assert_eq!(spell_number(1002), "one thousand and two");
+        assert_eq!(
+            spell_number(2_500_100),
+            "two million five hundred thousand one hundred"
+        );
+        assert_eq!(
+            spell_number_with(1_234, NumberStyle::American),
+            "one thousand two hundred thirty-four"
+        );
+        assert_eq!(
+            spell_number(i64::MIN),
+            "minus nine quintillion two hundred and twenty-three quadrillion three hundred and seventy-two \
+             trillion thirty-six billion eight hundred and fifty-four million seven hundred and seventy-five \
+             thousand eight hundred and eight"
+        );
+    }
+}
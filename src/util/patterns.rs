@@ -0,0 +1,52 @@
+/// Returns true if the word matches a crossword-style pattern, where `_` stands for any one letter and `*` for any
+/// run of letters, including none: "c_t_h" matches "catch", and "*ness" matches "kindness". Other characters must
+/// match exactly, ignoring case.
+pub fn matches_pattern(pattern: &str, word: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let word: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+    let (mut p, mut w) = (0, 0);
+    // The last '*' seen, and the position in the word it was tried against.
+    let mut star = None;
+    while w < word.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, w));
+                p += 1;
+            }
+            Some(c) if *c == '_' || *c == word[w] => {
+                p += 1;
+                w += 1;
+            }
+            // On a mismatch, let the last '*' swallow one more letter and try again.
+            _ => match star {
+                Some((star_p, star_w)) => {
+                    star = Some((star_p, star_w + 1));
+                    p = star_p + 1;
+                    w = star_w + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_test() {
+        assert!(matches_pattern("c_t_h", "catch"));
+        assert!(matches_pattern("C_T_H", "Cutch"));
+        assert!(!matches_pattern("c_t_h", "cat"));
+        assert!(matches_pattern("*ness", "kindness"));
+        assert!(matches_pattern("*ness", "ness"));
+        assert!(matches_pattern("b*n*a", "banana"));
+        assert!(!matches_pattern("b*n*x", "banana"));
+        assert!(matches_pattern("*", ""));
+        assert!(!matches_pattern("_", ""));
+        assert!(matches_pattern("", ""));
+    }
+}
@@ -0,0 +1,257 @@
+use super::match_case;
+
+/// Common nouns with irregular plurals, as (singular, plural) pairs.
+pub const IRREGULAR_PLURALS: [(&str, &str); 46] = [
+    ("child", "children"),
+    ("person", "people"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("mouse", "mice"),
+    ("louse", "lice"),
+    ("goose", "geese"),
+    ("foot", "feet"),
+    ("tooth", "teeth"),
+    ("ox", "oxen"),
+    ("die", "dice"),
+    ("sheep", "sheep"),
+    ("deer", "deer"),
+    ("fish", "fish"),
+    ("moose", "moose"),
+    ("swine", "swine"),
+    ("aircraft", "aircraft"),
+    ("series", "series"),
+    ("species", "species"),
+    ("cactus", "cacti"),
+    ("fungus", "fungi"),
+    ("nucleus", "nuclei"),
+    ("radius", "radii"),
+    ("alumnus", "alumni"),
+    ("stimulus", "stimuli"),
+    ("analysis", "analyses"),
+    ("basis", "bases"),
+    ("crisis", "crises"),
+    ("thesis", "theses"),
+    ("oasis", "oases"),
+    ("phenomenon", "phenomena"),
+    ("criterion", "criteria"),
+    ("datum", "data"),
+    ("index", "indices"),
+    ("appendix", "appendices"),
+    ("matrix", "matrices"),
+    ("vertex", "vertices"),
+    ("quiz", "quizzes"),
+    ("hero", "heroes"),
+    ("potato", "potatoes"),
+    ("tomato", "tomatoes"),
+    ("echo", "echoes"),
+    ("veto", "vetoes"),
+    ("torpedo", "torpedoes"),
+    ("volcano", "volcanoes"),
+    ("mosquito", "mosquitoes"),
+];
+
+/// Nouns ending in 'f' that change to "-ves" in the plural. Other words ending in 'f' just take an 's'.
+const F_TO_VES: [&str; 14] = [
+    "leaf", "loaf", "thief", "wolf", "calf", "half", "shelf", "elf", "self", "sheaf", "scarf",
+    "dwarf", "wharf", "hoof",
+];
+
+/// Nouns ending in "fe" that change to "-ves" in the plural. Other words ending in "fe" just take an 's'.
+const FE_TO_VES: [&str; 7] = [
+    "knife",
+    "wife",
+    "life",
+    "penknife",
+    "midwife",
+    "housewife",
+    "afterlife",
+];
+
+/// Nouns ending in 's' that take "-es" in the plural. Plurals ending in "-ses" are otherwise taken to have dropped
+/// only the 's', as in "houses".
+const S_TO_SES: [&str; 16] = [
+    "bus", "gas", "lens", "atlas", "alias", "bias", "canvas", "iris", "virus", "bonus", "campus",
+    "census", "chorus", "circus", "status", "walrus",
+];
+
+/// Nouns ending in "ie" whose plural ends in "-ies". Plurals ending in "-ies" are otherwise taken to come from '-y'.
+const IE_TO_IES: [&str; 14] = [
+    "movie", "cookie", "zombie", "calorie", "rookie", "prairie", "brownie", "goalie", "genie",
+    "pixie", "sortie", "hoodie", "selfie", "smoothie",
+];
+
+/// Returns the plural of an English noun, following the regular spelling rules and a table of common irregulars.
+/// The casing of the input is kept.
+pub fn pluralize(word: &str) -> String {
+    if word.is_empty() {
+        return String::new();
+    }
+    match_case(word, plural_of(&word.to_lowercase()))
+}
+
+/// Pluralizes a non-empty, lower-case word.
+fn plural_of(word: &str) -> String {
+    if let Some((_, plural)) = IRREGULAR_PLURALS.iter().find(|(s, _)| *s == word) {
+        return plural.to_string();
+    }
+    if F_TO_VES.iter().any(|w| word.ends_with(w)) {
+        return format!("{}ves", &word[..word.len() - 1]);
+    }
+    if FE_TO_VES.contains(&word) {
+        return format!("{}ves", &word[..word.len() - 2]);
+    }
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|e| word.ends_with(e))
+    {
+        return format!("{}es", word);
+    }
+    if let Some(stem) = word.strip_suffix('y') {
+        if stem.chars().last().is_some_and(|c| !"aeiou".contains(c)) {
+            return format!("{}ies", stem);
+        }
+    }
+    format!("{}s", word)
+}
+
+/// Returns the singular of an English noun, undoing the regular spelling rules and the table of common irregulars.
+/// Words that already look singular are returned unchanged, and the casing of the input is kept.
+pub fn singularize(word: &str) -> String {
+    if word.is_empty() {
+        return String::new();
+    }
+    match_case(word, singular_of(&word.to_lowercase()))
+}
+
+/// Returns true if the word looks like a plural noun. Nouns with the same singular and plural form, like "sheep",
+/// are treated as singular.
+pub fn is_plural(word: &str) -> bool {
+    !word.is_empty() && singular_of(&word.to_lowercase()) != word.to_lowercase()
+}
+
+/// Singularizes a non-empty, lower-case word.
+fn singular_of(word: &str) -> String {
+    if let Some((singular, _)) = IRREGULAR_PLURALS.iter().find(|(_, p)| *p == word) {
+        return singular.to_string();
+    }
+    if IRREGULAR_PLURALS.iter().any(|(s, _)| *s == word) {
+        return word.to_string();
+    }
+    if ["ss", "us", "is"].iter().any(|e| word.ends_with(e)) || S_TO_SES.contains(&word) {
+        return word.to_string();
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        if S_TO_SES.contains(&stem) {
+            return stem.to_string();
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ves") {
+        let f = format!("{}f", stem);
+        if F_TO_VES.iter().any(|w| f.ends_with(w)) {
+            return f;
+        }
+        let fe = format!("{}fe", stem);
+        if FE_TO_VES.contains(&fe.as_str()) {
+            return fe;
+        }
+    }
+    if let Some(stem) = word.strip_suffix('s') {
+        if IE_TO_IES.contains(&stem) {
+            return stem.to_string();
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ies") {
+        if stem.len() > 1 {
+            return format!("{}y", stem);
+        }
+    }
+    if ["sses", "xes", "ches", "shes", "zzes"]
+        .iter()
+        .any(|e| word.ends_with(e))
+    {
+        return word[..word.len() - 2].to_string();
+    }
+    match word.strip_suffix('s') {
+        Some(stem) => stem.to_string(),
+        None => word.to_string(),
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pluralize_test() {
+        let cases = [
+            ("cat", "cats"),
+            ("box", "boxes"),
+            ("church", "churches"),
+            ("bush", "bushes"),
+            ("glass", "glasses"),
+            ("city", "cities"),
+            ("day", "days"),
+            ("knife", "knives"),
+            ("wolf", "wolves"),
+            ("roof", "roofs"),
+            ("giraffe", "giraffes"),
+            ("carafe", "carafes"),
+            ("safe", "safes"),
+            ("midwife", "midwives"),
+            ("bus", "buses"),
+            ("lens", "lenses"),
+            ("movie", "movies"),
+            ("hero", "heroes"),
+            ("piano", "pianos"),
+            ("child", "children"),
+            ("sheep", "sheep"),
+            ("Mouse", "Mice"),
+            ("CITY", "CITIES"),
+            ("", ""),
+        ];
+        for (singular, plural) in cases {
+            assert_eq!(pluralize(singular), plural);
+        }
+    }
+
+    #[test]
+    fn singularize_test() {
+        let cases = [
+            ("cats", "cat"),
+            ("boxes", "box"),
+            ("churches", "church"),
+            ("glasses", "glass"),
+            ("houses", "house"),
+            ("cities", "city"),
+            ("days", "day"),
+            ("knives", "knife"),
+            ("midwives", "midwife"),
+            ("carafes", "carafe"),
+            ("buses", "bus"),
+            ("lenses", "lens"),
+            ("gases", "gas"),
+            ("lens", "lens"),
+            ("movies", "movie"),
+            ("cookies", "cookie"),
+            ("wolves", "wolf"),
+            ("olives", "olive"),
+            ("heroes", "hero"),
+            ("children", "child"),
+            ("Mice", "Mouse"),
+            ("sheep", "sheep"),
+            ("glass", "glass"),
+            ("cactus", "cactus"),
+            ("child", "child"),
+        ];
+        for (plural, singular) in cases {
+            assert_eq!(singularize(plural), singular);
+        }
+
+        assert!(is_plural("wolves"));
+        assert!(is_plural("People"));
+        assert!(!is_plural("wolf"));
+        assert!(!is_plural("sheep"));
+        assert!(!is_plural(""));
+    }
+}
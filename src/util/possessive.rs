@@ -0,0 +1,72 @@
+/// How [`possessive_with`] treats singular words that already end in 's'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PossessiveStyle {
+    /// "James's".
+    #[default]
+    ApostropheS,
+    /// "James'".
+    Apostrophe,
+}
+
+/// Returns the possessive form of a singular noun or name, as in "the wizard's", adding "'s" even after an 's'
+/// ("James's"). See [`possessive_with`] for the alternative style, and [`plural_possessive`] for plurals.
+pub fn possessive(word: &str) -> String {
+    possessive_with(word, PossessiveStyle::ApostropheS)
+}
+
+/// Returns the possessive form of a singular noun or name, using the given style for words ending in 's'.
+pub fn possessive_with(word: &str, style: PossessiveStyle) -> String {
+    if ends_with_s(word) && style == PossessiveStyle::Apostrophe {
+        format!("{}'", word)
+    } else {
+        format!("{}'{}", word, s_for(word))
+    }
+}
+
+/// Returns the possessive form of a plural noun: "the wizards'" for plurals ending in 's', and "the children's" for
+/// those that don't.
+pub fn plural_possessive(word: &str) -> String {
+    if ends_with_s(word) {
+        format!("{}'", word)
+    } else {
+        format!("{}'{}", word, s_for(word))
+    }
+}
+
+/// Returns true if the word ends with an 's' of either case.
+fn ends_with_s(word: &str) -> bool {
+    word.ends_with(['s', 'S'])
+}
+
+/// Returns the 's' to add to the word, upper-case if the word is shouted.
+fn s_for(word: &str) -> char {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic()).peekable();
+    if letters.peek().is_some() && word.chars().count() > 1 && letters.all(char::is_uppercase) {
+        'S'
+    } else {
+        's'
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn possessive_test() {
+        assert_eq!(possessive("the wizard"), "the wizard's");
+        assert_eq!(possessive("James"), "James's");
+        assert_eq!(
+            possessive_with("James", PossessiveStyle::Apostrophe),
+            "James'"
+        );
+        assert_eq!(
+            possessive_with("Aldric", PossessiveStyle::Apostrophe),
+            "Aldric's"
+        );
+        assert_eq!(plural_possessive("the wizards"), "the wizards'");
+        assert_eq!(plural_possessive("children"), "children's");
+        assert_eq!(possessive("ORC"), "ORC'S");
+    }
+}
@@ -0,0 +1,92 @@
+/// The consonants of a readable word, one for each four bits.
+const CONSONANTS: [char; 16] = [
+    'b', 'd', 'f', 'g', 'h', 'j', 'k', 'l', 'm', 'n', 'p', 'r', 's', 't', 'v', 'z',
+];
+/// The vowels of a readable word, one for each two bits.
+const VOWELS: [char; 4] = ['a', 'i', 'o', 'u'];
+
+/// Encodes bytes as pronounceable words separated by hyphens, using the proquint alphabet so the output matches
+/// other proquint implementations. Each pair of bytes becomes a consonant-vowel-consonant-vowel-consonant word
+/// ("lusab"), and an odd byte at the end becomes a consonant-vowel-vowel word. Decode with [`decode_readable`].
+pub fn encode_readable(bytes: &[u8]) -> String {
+    let mut words = Vec::with_capacity(bytes.len().div_ceil(2));
+    for pair in bytes.chunks(2) {
+        let word = match *pair {
+            [high, low] => {
+                let n = u16::from_be_bytes([high, low]) as usize;
+                [
+                    CONSONANTS[n >> 12],
+                    VOWELS[(n >> 10) & 0x3],
+                    CONSONANTS[(n >> 6) & 0xf],
+                    VOWELS[(n >> 4) & 0x3],
+                    CONSONANTS[n & 0xf],
+                ]
+                .iter()
+                .collect::<String>()
+            }
+            [byte] => {
+                let n = byte as usize;
+                [CONSONANTS[n >> 4], VOWELS[(n >> 2) & 0x3], VOWELS[n & 0x3]]
+                    .iter()
+                    .collect::<String>()
+            }
+            _ => unreachable!(),
+        };
+        words.push(word);
+    }
+    words.join("-")
+}
+
+/// Decodes words written by [`encode_readable`] back into bytes, ignoring case. Returns `None` if the text isn't a
+/// valid encoding.
+pub fn decode_readable(s: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    if s.is_empty() {
+        return Some(bytes);
+    }
+    let consonant = |c: char| CONSONANTS.iter().position(|x| *x == c);
+    let vowel = |c: char| VOWELS.iter().position(|x| *x == c);
+    let words: Vec<&str> = s.split('-').collect();
+    for (i, word) in words.iter().enumerate() {
+        let chars: Vec<char> = word.chars().map(|c| c.to_ascii_lowercase()).collect();
+        match chars[..] {
+            [c1, v1, c2, v2, c3] => {
+                let n = consonant(c1)? << 12
+                    | vowel(v1)? << 10
+                    | consonant(c2)? << 6
+                    | vowel(v2)? << 4
+                    | consonant(c3)?;
+                bytes.extend_from_slice(&(n as u16).to_be_bytes());
+            }
+            // Only the last word can hold a single byte.
+            [c, v1, v2] if i == words.len() - 1 => {
+                bytes.push((consonant(c)? << 4 | vowel(v1)? << 2 | vowel(v2)?) as u8);
+            }
+            _ => return None,
+        }
+    }
+    Some(bytes)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readable_test() {
+        // 127.0.0.1, from the proquint specification.
+        assert_eq!(encode_readable(&[127, 0, 0, 1]), "lusab-babad");
+        assert_eq!(decode_readable("LUSAB-babad"), Some(vec![127, 0, 0, 1]));
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode_readable(&encode_readable(&bytes)), Some(bytes));
+        let odd = [0xde, 0xad, 0xbe];
+        assert_eq!(decode_readable(&encode_readable(&odd)).unwrap(), odd);
+        assert_eq!(decode_readable(""), Some(Vec::new()));
+
+        assert_eq!(decode_readable("lusab-baa"), Some(vec![127, 0, 0]));
+        assert_eq!(decode_readable("baa-lusab"), None);
+        assert_eq!(decode_readable("lusax"), None);
+    }
+}
@@ -0,0 +1,118 @@
+/// Spellings of long vowel sounds, longest first, with the symbol each is reduced to. Long vowels are written as
+/// capitals so they can't be confused with short ones.
+const VOWEL_SPELLINGS: [(&str, &str); 13] = [
+    ("eigh", "A"),
+    ("igh", "I"),
+    ("ay", "A"),
+    ("ai", "A"),
+    ("ey", "A"),
+    ("ee", "E"),
+    ("ea", "E"),
+    ("ie", "I"),
+    ("oa", "O"),
+    ("oe", "O"),
+    ("oo", "U"),
+    ("ew", "U"),
+    ("ue", "U"),
+];
+
+/// Returns true if two different words rhyme, going by their spelling: "night" and "kite", "cat" and "hat". Words
+/// rhyme when they sound the same from their last stressed vowel onwards, which is approximated by reducing common
+/// spellings of the same sound ("igh", "i_e", final "y") to one form, and by passing over unstressed endings such
+/// as the "-y" of "happy" or the "-le" of "table". Identical words don't count as rhyming.
+pub fn rhymes_with(a: &str, b: &str) -> bool {
+    let (a, b) = (a.to_lowercase(), b.to_lowercase());
+    if a == b {
+        return false;
+    }
+    match (rime(&a), rime(&b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Returns the sound of a lower-case word from its last full vowel onwards, or `None` if it has no vowels.
+fn rime(word: &str) -> Option<String> {
+    let mut chars: Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+    let is_vowel = |c: char| "aeiouAEIOU".contains(c);
+
+    // A silent 'e' after a single vowel and consonant makes the vowel long: "kite" sounds like "kIt".
+    let n = chars.len();
+    if n >= 3
+        && chars[n - 1] == 'e'
+        && !is_vowel(chars[n - 2])
+        && is_vowel(chars[n - 3])
+        && (n == 3 || !is_vowel(chars[n - 4]))
+    {
+        chars[n - 3] = chars[n - 3].to_ascii_uppercase();
+        chars.pop();
+    }
+
+    let mut sound: String = chars.into_iter().collect();
+    for (spelling, symbol) in VOWEL_SPELLINGS {
+        sound = sound.replace(spelling, symbol);
+    }
+    // A final 'y' is a long 'i' in short words ("fly") and an unstressed 'e' in longer ones ("happy").
+    let mut weak_y = false;
+    if let Some(stem) = sound.strip_suffix('y') {
+        if !stem.ends_with(is_vowel) {
+            weak_y = stem.chars().any(is_vowel);
+            sound = format!("{}{}", stem, if weak_y { "E" } else { "I" });
+        }
+    }
+    sound = sound.replace("ck", "k").replace("ph", "f");
+
+    // Double consonants sound like single ones.
+    let mut chars: Vec<char> = sound.chars().collect();
+    chars.dedup_by(|a, b| a == b && !is_vowel(*a));
+
+    // Unstressed endings never carry the rhyme, so it starts at the vowel before them: "table" rhymes on "able".
+    let n = chars.len();
+    let weak = match chars[..] {
+        [.., c, 'l', 'e'] | [.., c, 'e', 'r']
+            if !is_vowel(c) && chars[..n - 2].iter().any(|c| is_vowel(*c)) =>
+        {
+            2
+        }
+        _ if weak_y => 1,
+        _ => 0,
+    };
+    let start = chars[..n - weak]
+        .iter()
+        .rposition(|c| is_vowel(*c))
+        .or_else(|| chars.iter().rposition(|c| is_vowel(*c)))?;
+    // Include the whole group of vowels, not just its last letter.
+    let start = chars[..start]
+        .iter()
+        .rposition(|c| !is_vowel(*c))
+        .map_or(0, |i| i + 1);
+    Some(chars[start..].iter().collect())
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rhyme_test() {
+        assert!(rhymes_with("night", "kite"));
+        assert!(rhymes_with("Night", "light"));
+        assert!(rhymes_with("cat", "hat"));
+        assert!(rhymes_with("fly", "high"));
+        assert!(rhymes_with("happy", "sappy"));
+        assert!(rhymes_with("stack", "yak"));
+        assert!(rhymes_with("day", "weigh"));
+        assert!(!rhymes_with("cat", "cart"));
+        assert!(!rhymes_with("sit", "night"));
+        assert!(!rhymes_with("cat", "cat"));
+        assert!(!rhymes_with("hmm", "brr"));
+
+        assert!(rhymes_with("table", "cable"));
+        assert!(rhymes_with("butter", "cutter"));
+        assert!(!rhymes_with("happy", "city"));
+        assert!(!rhymes_with("table", "the"));
+        assert!(!rhymes_with("table", "purple"));
+        assert!(!rhymes_with("butter", "her"));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Frequency, Letter, NGramSampler};
+use std::{collections::HashMap, sync::OnceLock};
+
+/// The value of each tile in English Scrabble, from 'a' to 'z'.
+const SCRABBLE: [u32; 26] = [
+    1, 3, 3, 2, 1, 4, 2, 4, 1, 8, 5, 1, 3, 1, 1, 3, 10, 1, 1, 1, 1, 4, 4, 8, 4, 10,
+];
+
+/// The highest score given to a letter by [`LetterScores::from_frequencies`], matching Scrabble's 'q' and 'z'.
+const MAX_SCORE: u32 = 10;
+
+/// Points for each letter, for scoring words in word games. Letters without a score are worth nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterScores {
+    scores: HashMap<char, u32>,
+}
+
+impl LetterScores {
+    /// Builds a table from the given letters and their scores. Letters are stored in lower case.
+    pub fn new(scores: impl IntoIterator<Item = (char, u32)>) -> Self {
+        Self {
+            scores: scores
+                .into_iter()
+                .map(|(c, score)| (c.to_ascii_lowercase(), score))
+                .collect(),
+        }
+    }
+
+    /// Builds the standard English Scrabble table, where common letters like 'e' are worth 1 and 'q' and 'z' 10.
+    pub fn scrabble() -> Self {
+        Self::new(('a'..='z').zip(SCRABBLE))
+    }
+
+    /// Builds a table where rarer letters score more, from 1 for the most common letter up to 10. Each halving of a
+    /// letter's frequency relative to the most common adds a point, so a language model's letter frequencies give a
+    /// table that fits its alphabet.
+    pub fn from_frequencies(letters: &NGramSampler<Letter>) -> Self {
+        let letters = letters.sample_set();
+        let max = letters.iter().map(|l| l.frequency()).fold(0.0, f32::max);
+        Self::new(letters.into_iter().map(|l| {
+            let score = if l.frequency() > 0.0 {
+                ((max / l.frequency()).log2().round() as u32 + 1).min(MAX_SCORE)
+            } else {
+                MAX_SCORE
+            };
+            (l.chars[0], score)
+        }))
+    }
+
+    /// Returns the score of a single letter, ignoring case.
+    pub fn get(&self, c: char) -> u32 {
+        self.scores
+            .get(&c.to_ascii_lowercase())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Returns the total score of a word's letters.
+    pub fn score(&self, word: &str) -> u32 {
+        word.chars().map(|c| self.get(c)).sum()
+    }
+}
+
+impl Default for LetterScores {
+    fn default() -> Self {
+        Self::scrabble()
+    }
+}
+
+/// Returns the Scrabble score of a word, ignoring case: "quiz" scores 22. Characters that aren't English letters
+/// score nothing. Use [`LetterScores`] for other tables.
+pub fn letter_score(word: &str) -> u32 {
+    static SCORES: OnceLock<LetterScores> = OnceLock::new();
+    SCORES.get_or_init(LetterScores::scrabble).score(word)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_test() {
+        assert_eq!(letter_score("quiz"), 22);
+        assert_eq!(letter_score("Cat!"), 5);
+        assert_eq!(letter_score(""), 0);
+
+        let scores = LetterScores::new([('a', 2), ('B', 5)]);
+        assert_eq!(scores.score("abc"), 7);
+
+        let scores = LetterScores::from_frequencies(&NGramSampler::default());
+        assert_eq!(scores.get('e'), 1);
+        assert!(scores.get('z') > scores.get('t'));
+        assert!(scores.get('z') <= MAX_SCORE);
+    }
+}
@@ -0,0 +1,153 @@
+/// Returns the American Soundex code of a word: its first letter followed by three digits for the consonant sounds
+/// after it ("Robert" and "Rupert" are both "R163"). As the first letter is kept as written, "Kaylin" and "Caylin"
+/// have different codes; use [`metaphone`] to catch those. Non-ASCII letters are ignored, and a word without any
+/// letters has an empty code.
+pub fn soundex(word: &str) -> String {
+    let digit = |c: char| match c {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        _ => None,
+    };
+    let mut letters = word
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase());
+    let Some(first) = letters.next() else {
+        return String::new();
+    };
+
+    let mut code = String::from(first);
+    let mut previous = digit(first);
+    for c in letters {
+        let d = digit(c);
+        if d.is_some() && d != previous {
+            code.extend(d);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        // Vowels separate repeated sounds, but 'h' and 'w' don't.
+        if c != 'H' && c != 'W' {
+            previous = d;
+        }
+    }
+    format!("{:0<4}", code)
+}
+
+/// Returns the Metaphone key of a word, which spells out its consonant sounds so words that sound alike share a key:
+/// "Kaylin" and "Caylin" are both "KLN", and "Smith" is "SM0" (with '0' for "th"). Vowels are only kept at the start
+/// of a word. This is Lawrence Philips' original Metaphone; non-ASCII letters are ignored.
+pub fn metaphone(word: &str) -> String {
+    let mut chars: Vec<char> = word
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    // Doubled letters sound like single ones, except "cc" ("accent").
+    chars.dedup_by(|a, b| a == b && *a != 'C');
+
+    // Some starting letters are silent or change sound.
+    match chars.as_slice() {
+        ['K', 'N', ..] | ['G', 'N', ..] | ['P', 'N', ..] | ['A', 'E', ..] | ['W', 'R', ..] => {
+            chars.remove(0);
+        }
+        ['X', ..] => chars[0] = 'S',
+        ['W', 'H', ..] => {
+            chars.remove(1);
+        }
+        _ => {}
+    }
+
+    let is_vowel = |c: Option<&char>| c.is_some_and(|c| "AEIOU".contains(*c));
+    let at = |i: usize| chars.get(i).copied();
+    let mut key = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let previous = i.checked_sub(1).and_then(at);
+        let (next, after) = (at(i + 1), at(i + 2));
+        let front = matches!(next, Some('E' | 'I' | 'Y'));
+        match c {
+            'A' | 'E' | 'I' | 'O' | 'U' if i == 0 => key.push(c),
+            'A' | 'E' | 'I' | 'O' | 'U' => {}
+            // A 'b' after an 'm' at the end is silent ("dumb").
+            'B' if previous == Some('M') && next.is_none() => {}
+            'C' if next == Some('H') && previous == Some('S') => key.push('K'),
+            'C' if next == Some('H') || (next == Some('I') && after == Some('A')) => key.push('X'),
+            'C' if front && previous == Some('S') => {}
+            'C' if front => key.push('S'),
+            'C' => key.push('K'),
+            'D' if next == Some('G') && matches!(after, Some('E' | 'I' | 'Y')) => key.push('J'),
+            'D' => key.push('T'),
+            // A 'g' is silent in "gh" unless it ends the word or comes before a vowel, in a final "gn" or "gned", and
+            // after the 'd' of a soft "dg".
+            'G' if next == Some('H') && after.is_some() && !is_vowel(after.as_ref()) => {}
+            'G' if next == Some('N') && (after.is_none() || chars[i + 1..] == ['N', 'E', 'D']) => {}
+            'G' if front && previous == Some('D') => {}
+            'G' if front => key.push('J'),
+            'G' => key.push('K'),
+            'H' if matches!(previous, Some('C' | 'G' | 'P' | 'S' | 'T')) => {}
+            'H' if is_vowel(previous.as_ref()) && !is_vowel(next.as_ref()) => {}
+            'K' if previous == Some('C') => {}
+            'P' if next == Some('H') => key.push('F'),
+            'Q' => key.push('K'),
+            'S' if next == Some('H') => key.push('X'),
+            'S' if next == Some('I') && matches!(after, Some('O' | 'A')) => key.push('X'),
+            'T' if next == Some('I') && matches!(after, Some('O' | 'A')) => key.push('X'),
+            'T' if next == Some('H') => key.push('0'),
+            'T' if next == Some('C') && after == Some('H') => {}
+            'V' => key.push('F'),
+            'W' | 'Y' if !is_vowel(next.as_ref()) => {}
+            'X' => key.push_str("KS"),
+            'Z' => key.push('S'),
+            _ => key.push(c),
+        }
+    }
+    key
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soundex_test() {
+        let cases = [
+            ("Robert", "R163"),
+            ("Rupert", "R163"),
+            ("Rubin", "R150"),
+            ("Ashcraft", "A261"),
+            ("Tymczak", "T522"),
+            ("Pfister", "P236"),
+            ("Honeyman", "H555"),
+            ("Lee", "L000"),
+            ("", ""),
+        ];
+        for (word, code) in cases {
+            assert_eq!(soundex(word), code, "{}", word);
+        }
+    }
+
+    #[test]
+    fn metaphone_test() {
+        let cases = [
+            ("Kaylin", "KLN"),
+            ("Caylin", "KLN"),
+            ("Smith", "SM0"),
+            ("knight", "NT"),
+            ("Wright", "RT"),
+            ("school", "SKL"),
+            ("Philip", "FLP"),
+            ("Xavier", "SFR"),
+            ("dumb", "TM"),
+            ("Edge", "EJ"),
+            ("", ""),
+        ];
+        for (word, key) in cases {
+            assert_eq!(metaphone(word), key, "{}", word);
+        }
+    }
+}
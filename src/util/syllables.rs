@@ -0,0 +1,122 @@
+/// Consonant pairs that begin a syllable together rather than being split between two.
+const ONSET_PAIRS: [[char; 2]; 17] = [
+    ['b', 'l'],
+    ['b', 'r'],
+    ['c', 'l'],
+    ['c', 'r'],
+    ['d', 'r'],
+    ['f', 'l'],
+    ['f', 'r'],
+    ['g', 'l'],
+    ['g', 'r'],
+    ['p', 'l'],
+    ['p', 'r'],
+    ['t', 'r'],
+    ['t', 'h'],
+    ['s', 'h'],
+    ['c', 'h'],
+    ['p', 'h'],
+    ['w', 'h'],
+];
+
+/// Splits a word into its syllables, using spelling heuristics rather than pronunciation:
+/// - A single consonant between vowels closes the first syllable ("wiz-ard"), unless that syllable is a lone
+///   vowel at the start of the word or has a vowel pair ("o-pen", "rea-son").
+/// - Consonant clusters are split after their first consonant ("sil-ver"), keeping blends such as "bl" and "th"
+///   together at the start of the next syllable ("ta-ble", "hun-dred").
+/// - A final 'e' after a consonant is silent ("whale"), except in "-le" endings.
+pub fn syllabify(word: &str) -> Vec<&str> {
+    let offsets: Vec<usize> = word.char_indices().map(|(i, _)| i).collect();
+    let chars: Vec<char> = word.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let n = chars.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut vowels = Vec::<bool>::with_capacity(n);
+    for i in 0..n {
+        let vowel = match chars[i] {
+            'a' | 'e' | 'i' | 'o' => true,
+            // The 'u' in "qu" acts as a consonant.
+            'u' => i == 0 || chars[i - 1] != 'q',
+            // A 'y' is only a vowel when it follows a consonant.
+            'y' => i > 0 && !vowels[i - 1],
+            _ => false,
+        };
+        vowels.push(vowel);
+    }
+
+    // Find each run of vowels, as the nucleus of a syllable.
+    let mut nuclei = Vec::<(usize, usize)>::new();
+    for (i, vowel) in vowels.iter().enumerate() {
+        if *vowel {
+            match nuclei.last_mut() {
+                Some((_, end)) if *end == i => *end = i + 1,
+                _ => nuclei.push((i, i + 1)),
+            }
+        }
+    }
+    if let [.., _, (start, _)] = nuclei[..] {
+        let silent_e = start == n - 1 && chars[start] == 'e';
+        let le_ending = chars[n - 2] == 'l' && !vowels[n - 3];
+        if silent_e && !le_ending {
+            nuclei.pop();
+        }
+    }
+
+    let mut bounds = vec![0];
+    for pair in nuclei.windows(2) {
+        let ((start, end), (next, _)) = (pair[0], pair[1]);
+        let split = if next - end == 1 {
+            if end - start == 1 && start > 0 {
+                next
+            } else {
+                end
+            }
+        } else if ONSET_PAIRS.contains(&[chars[next - 2], chars[next - 1]]) {
+            next - 2
+        } else if chars[next - 2..next] == ['c', 'k'] {
+            next
+        } else {
+            end + 1
+        };
+        bounds.push(split);
+    }
+
+    let mut syllables = Vec::with_capacity(bounds.len());
+    for (i, start) in bounds.iter().enumerate() {
+        let from = offsets[*start];
+        let to = bounds.get(i + 1).map_or(word.len(), |end| offsets[*end]);
+        syllables.push(&word[from..to]);
+    }
+    syllables
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syllabify_test() {
+        let cases: [(&str, &[&str]); 14] = [
+            ("wizard", &["wiz", "ard"]),
+            ("open", &["o", "pen"]),
+            ("reason", &["rea", "son"]),
+            ("silver", &["sil", "ver"]),
+            ("table", &["ta", "ble"]),
+            ("Children", &["Chil", "dren"]),
+            ("pocket", &["pock", "et"]),
+            ("whale", &["whale"]),
+            ("player", &["play", "er"]),
+            ("queen", &["queen"]),
+            ("beautiful", &["beau", "tif", "ul"]),
+            ("the", &["the"]),
+            ("rhythm", &["rhythm"]),
+            ("", &[]),
+        ];
+        for (word, syllables) in cases {
+            assert_eq!(syllabify(word), syllables);
+        }
+    }
+}
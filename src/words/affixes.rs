@@ -0,0 +1,40 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+/// A weighted table of affixes, such as the suffixes of surnames or the prefixes of place names.
+#[derive(Debug, Clone)]
+pub struct Affixes {
+    affixes: Vec<String>,
+    weights: Option<WeightedIndex<f32>>,
+}
+
+impl Affixes {
+    /// Builds a table from pairs of affixes and their relative weights. Panics if a weight is negative.
+    pub fn new<S: Into<String>>(affixes: impl IntoIterator<Item = (S, f32)>) -> Self {
+        let (affixes, weights): (Vec<String>, Vec<f32>) =
+            affixes.into_iter().map(|(a, w)| (a.into(), w)).unzip();
+        assert!(
+            weights.iter().all(|w| *w >= 0.0 && w.is_finite()),
+            "Affix weights must not be negative"
+        );
+        Self {
+            affixes,
+            weights: WeightedIndex::new(weights).ok(),
+        }
+    }
+
+    /// Takes a random affix, using the weights. Returns `None` if the table is empty.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&str> {
+        let weights = self.weights.as_ref()?;
+        Some(&self.affixes[weights.sample(rng)])
+    }
+
+    /// Returns every affix in the table.
+    pub fn affixes(&self) -> &[String] {
+        &self.affixes
+    }
+
+    /// Returns true if the table has no affixes that can be sampled.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_none()
+    }
+}
@@ -0,0 +1,104 @@
+use crate::util::{compound, portmanteau};
+use rand::{seq::SliceRandom, Rng};
+
+use super::{syllable_count, WordBuilder, WordLength};
+
+/// How many joins to try when looking for one of a requested length.
+const LENGTH_ATTEMPTS: usize = 16;
+
+/// Builds compound words by joining a word from one list to a word from another, such as "fire" + "brand", or by
+/// blending the two into a portmanteau. Useful for naming items and spells from hand-picked parts.
+#[derive(Debug, Clone)]
+pub struct CompoundBuilder {
+    firsts: Vec<String>,
+    seconds: Vec<String>,
+    blend_chance: f64,
+}
+
+impl CompoundBuilder {
+    /// Builds a compound builder that joins words from `firsts` to words from `seconds`. Panics if either list is
+    /// empty.
+    pub fn new<S: AsRef<str>>(firsts: &[S], seconds: &[S]) -> Self {
+        assert!(
+            !firsts.is_empty() && !seconds.is_empty(),
+            "Compound builder needs words for both parts"
+        );
+        let owned = |words: &[S]| words.iter().map(|w| w.as_ref().to_string()).collect();
+        Self {
+            firsts: owned(firsts),
+            seconds: owned(seconds),
+            blend_chance: 0.0,
+        }
+    }
+
+    /// Sets the probability that the words are blended into a portmanteau rather than joined whole. Panics if
+    /// `chance` is not within `[0, 1]`.
+    pub fn blend_chance(mut self, chance: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&chance),
+            "Chance must be within [0, 1], got {}",
+            chance
+        );
+        self.blend_chance = chance;
+        self
+    }
+
+    /// Joins a random pair of words.
+    fn join<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let first = self.firsts.choose(rng).unwrap();
+        let second = self.seconds.choose(rng).unwrap();
+        if rng.gen_bool(self.blend_chance) {
+            if let Some(blend) = portmanteau(first, second) {
+                trace!(first = %first, second = %second, blend = %blend, "blended words");
+                return blend;
+            }
+        }
+        compound(first, second)
+    }
+}
+
+impl WordBuilder for CompoundBuilder {
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.join(rng)
+    }
+
+    /// Builds a compound as close to the given length as the word lists allow, from a handful of random joins.
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        let distance = |word: &str| match length {
+            WordLength::None => 0,
+            WordLength::Chars(i) => word.chars().count().abs_diff(i as usize),
+            WordLength::Syllables(i) => syllable_count(word).abs_diff(i as usize),
+        };
+        let mut best = self.join(rng);
+        for _ in 1..LENGTH_ATTEMPTS {
+            if distance(&best) == 0 {
+                break;
+            }
+            let word = self.join(rng);
+            if distance(&word) < distance(&best) {
+                best = word;
+            }
+        }
+        best
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compound_builder_test() {
+        let mut rng = rand::thread_rng();
+        let cb = CompoundBuilder::new(&["Fire", "Storm", "Frost"], &["brand", "caller", "bite"]);
+        for _ in 0..20 {
+            let word = cb.build(&mut rng);
+            assert!(["Fire", "Storm", "Frost"]
+                .iter()
+                .any(|w| word.starts_with(w)));
+        }
+        let short = cb.build_length_seeded(WordLength::Chars(8), 1);
+        assert!(short.len() <= 9, "{}", short);
+    }
+}
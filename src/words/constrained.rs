@@ -0,0 +1,227 @@
+use rand::Rng;
+use std::{collections::HashSet, error::Error, fmt};
+
+use super::{WordBuilder, WordFilter, WordLength};
+
+/// Returned when a [`ConstrainedBuilder`] fails to build a valid word within its attempt limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttemptsExhausted {
+    /// How many words were built and rejected.
+    pub attempts: usize,
+}
+
+impl fmt::Display for AttemptsExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "No word met the constraints after {} attempts",
+            self.attempts
+        )
+    }
+}
+
+impl Error for AttemptsExhausted {}
+
+/// Wraps another builder, rebuilding words until they meet a set of constraints. All text comparisons ignore case.
+#[derive(Debug, Clone)]
+pub struct ConstrainedBuilder<B: WordBuilder> {
+    inner: B,
+    min_len: usize,
+    max_len: usize,
+    prefix: Option<String>,
+    required: Vec<String>,
+    banned_substrings: Vec<String>,
+    banned_words: HashSet<String>,
+    filter: Option<WordFilter>,
+    max_attempts: usize,
+}
+
+impl<B: WordBuilder> ConstrainedBuilder<B> {
+    /// Wraps the given builder, with no constraints and a limit of 1000 attempts.
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            min_len: 0,
+            max_len: usize::MAX,
+            prefix: None,
+            required: Vec::new(),
+            banned_substrings: Vec::new(),
+            banned_words: HashSet::new(),
+            filter: None,
+            max_attempts: 1000,
+        }
+    }
+
+    /// Rejects words with fewer characters than `len`.
+    pub fn min_len(mut self, len: usize) -> Self {
+        self.min_len = len;
+        self
+    }
+
+    /// Rejects words with more characters than `len`.
+    pub fn max_len(mut self, len: usize) -> Self {
+        self.max_len = len;
+        self
+    }
+
+    /// Rejects words that don't start with `prefix`.
+    pub fn starts_with(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_lowercase());
+        self
+    }
+
+    /// Rejects words that don't contain `s`. Can be called repeatedly to require several substrings.
+    pub fn contains(mut self, s: &str) -> Self {
+        self.required.push(s.to_lowercase());
+        self
+    }
+
+    /// Rejects words that contain `s` anywhere within them.
+    pub fn ban_substring(mut self, s: &str) -> Self {
+        self.banned_substrings.push(s.to_lowercase());
+        self
+    }
+
+    /// Rejects words that are exactly `word`.
+    pub fn ban_word(mut self, word: &str) -> Self {
+        self.banned_words.insert(word.to_lowercase());
+        self
+    }
+
+    /// Rejects words that are exactly any of `words`, such as a profanity list.
+    pub fn ban_words<S: AsRef<str>>(mut self, words: impl IntoIterator<Item = S>) -> Self {
+        self.banned_words
+            .extend(words.into_iter().map(|w| w.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Rejects words caught by the given blocklist.
+    pub fn filter(mut self, filter: WordFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets how many words to build before giving up.
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Returns the wrapped builder.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Returns true if the word meets every constraint.
+    pub fn is_valid(&self, word: &str) -> bool {
+        self.meets_constraints(word) && !self.is_blocked(word)
+    }
+
+    /// Returns true if the word meets every constraint other than the blocklist.
+    fn meets_constraints(&self, word: &str) -> bool {
+        let len = word.chars().count();
+        let word = word.to_lowercase();
+        len >= self.min_len
+            && len <= self.max_len
+            && self.prefix.as_ref().is_none_or(|p| word.starts_with(p))
+            && self.required.iter().all(|s| word.contains(s))
+            && !self.banned_substrings.iter().any(|s| word.contains(s))
+            && !self.banned_words.contains(&word)
+    }
+
+    /// Returns true if the word is caught by the blocklist.
+    fn is_blocked(&self, word: &str) -> bool {
+        self.filter.as_ref().is_some_and(|f| f.is_blocked(word))
+    }
+
+    /// Builds a word that meets every constraint, or returns an error once the attempt limit is reached.
+    pub fn try_build<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<String, AttemptsExhausted> {
+        self.retry(|rng| self.inner.build(rng), rng)
+    }
+
+    /// Builds a word of the given length that meets every constraint, or returns an error once the attempt limit is
+    /// reached.
+    pub fn try_build_length<R: Rng + ?Sized>(
+        &self,
+        length: WordLength,
+        rng: &mut R,
+    ) -> Result<String, AttemptsExhausted> {
+        self.retry(|rng| self.inner.build_length(length.clone(), rng), rng)
+    }
+
+    /// Builds words until one is valid or the attempt limit is reached.
+    fn retry<R: Rng + ?Sized>(
+        &self,
+        build: impl Fn(&mut R) -> String,
+        rng: &mut R,
+    ) -> Result<String, AttemptsExhausted> {
+        for _ in 0..self.max_attempts {
+            let word = build(rng);
+            if !self.meets_constraints(&word) {
+                trace!(word = %word, "rejected word by constraints");
+            } else if self.is_blocked(&word) {
+                trace!(word = %word, "rejected word by blocklist");
+            } else {
+                return Ok(word);
+            }
+        }
+        Err(AttemptsExhausted {
+            attempts: self.max_attempts,
+        })
+    }
+}
+
+impl<B: WordBuilder> WordBuilder for ConstrainedBuilder<B> {
+    /// Builds a word that meets every constraint. Panics if none is found within the attempt limit; use
+    /// [`ConstrainedBuilder::try_build`] to handle that case.
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.try_build(rng).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds a word of the given length that meets every constraint. Panics if none is found within the attempt
+    /// limit; use [`ConstrainedBuilder::try_build_length`] to handle that case.
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        self.try_build_length(length, rng)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::SyllableBuilder;
+
+    #[test]
+    fn constrained_test() {
+        let mut rng = rand::thread_rng();
+        let cb = ConstrainedBuilder::new(SyllableBuilder::default())
+            .min_len(4)
+            .max_len(8)
+            .starts_with("b")
+            .ban_substring("e");
+
+        for _ in 0..100 {
+            let word = cb.build(&mut rng);
+            assert!((4..=8).contains(&word.len()), "{}", word);
+            assert!(word.starts_with('b') && !word.contains('e'), "{}", word);
+        }
+    }
+
+    #[test]
+    fn exhausted_test() {
+        let mut rng = rand::thread_rng();
+        let cb = ConstrainedBuilder::new(SyllableBuilder::default())
+            .contains("xyzzy")
+            .max_attempts(10);
+        assert_eq!(
+            cb.try_build(&mut rng),
+            Err(AttemptsExhausted { attempts: 10 })
+        );
+        assert!(!cb.clone().ban_word("Xyzzy").is_valid("xyzzy"));
+
+        let mut filter = WordFilter::new();
+        filter.block("zz");
+        assert!(!cb.filter(filter).is_valid("xyzzy"));
+    }
+}
@@ -0,0 +1,129 @@
+/// A blocklist of words that generated words must not contain. Matches are case-insensitive and include words
+/// embedded inside others, as generated names are prone to stumbling into unfortunate letter sequences. Exempt words
+/// excuse the matches that fall inside them, so blocking "rape" needn't block "Grapewood".
+#[derive(Debug, Clone, Default)]
+pub struct WordFilter {
+    blocked: Vec<String>,
+    exempt: Vec<String>,
+}
+
+impl WordFilter {
+    /// Builds a new, empty filter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a filter from the crate's bundled list of English profanity, with ordinary words that contain it
+    /// ("Hancock", "parse") exempt.
+    #[cfg(feature = "blocklist")]
+    pub fn english() -> Self {
+        let mut filter = Self::new();
+        filter.extend(include_str!("../blocklist.txt").lines());
+        for word in include_str!("../allowlist.txt").lines() {
+            filter.exempt(word);
+        }
+        filter
+    }
+
+    /// Blocks the given word, and any word containing it.
+    pub fn block(&mut self, word: &str) {
+        let word = word.trim().to_lowercase();
+        if !word.is_empty() && !self.blocked.contains(&word) {
+            self.blocked.push(word);
+        }
+    }
+
+    /// Blocks all of the given words.
+    pub fn extend<S: AsRef<str>>(&mut self, words: impl IntoIterator<Item = S>) {
+        for word in words {
+            self.block(word.as_ref());
+        }
+    }
+
+    /// Removes the given word from the blocklist.
+    pub fn allow(&mut self, word: &str) {
+        let word = word.trim().to_lowercase();
+        self.blocked.retain(|b| *b != word);
+    }
+
+    /// Exempts the given word, so blocked words found entirely inside it are let through. Blocked words elsewhere in
+    /// the same word are still caught.
+    pub fn exempt(&mut self, word: &str) {
+        let word = word.trim().to_lowercase();
+        if !word.is_empty() && !self.exempt.contains(&word) {
+            self.exempt.push(word);
+        }
+    }
+
+    /// Returns the blocked words.
+    pub fn blocked(&self) -> &[String] {
+        &self.blocked
+    }
+
+    /// Returns the exempt words.
+    pub fn exempted(&self) -> &[String] {
+        &self.exempt
+    }
+
+    /// Returns true if the word contains anything on the blocklist outside of an exempt word.
+    pub fn is_blocked(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        let exempt: Vec<(usize, usize)> = self
+            .exempt
+            .iter()
+            .flat_map(|e| word.match_indices(e.as_str()))
+            .map(|(i, e)| (i, i + e.len()))
+            .collect();
+        self.blocked.iter().any(|b| {
+            word.match_indices(b.as_str()).any(|(i, b)| {
+                !exempt
+                    .iter()
+                    .any(|(start, end)| *start <= i && i + b.len() <= *end)
+            })
+        })
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_test() {
+        let mut filter = WordFilter::new();
+        filter.extend(["grok", "Zorp"]);
+        assert!(filter.is_blocked("Agrokan"));
+        assert!(filter.is_blocked("ZORPA"));
+        assert!(!filter.is_blocked("Garok"));
+
+        filter.allow("ZORP");
+        assert!(!filter.is_blocked("zorpa"));
+
+        filter.exempt("Grokken");
+        assert!(!filter.is_blocked("Agrokken"));
+        assert!(filter.is_blocked("Grokkengrok"));
+        assert!(filter.is_blocked("Agrokan"));
+    }
+
+    #[cfg(feature = "blocklist")]
+    #[test]
+    fn english_filter_test() {
+        let filter = WordFilter::english();
+        assert!(!filter.blocked().is_empty());
+        assert!(filter.is_blocked("Shitley"));
+        assert!(filter.is_blocked("Cockton"));
+        assert!(!filter.is_blocked("Ashford"));
+        for word in [
+            "Hancock",
+            "Peacock",
+            "grape",
+            "parse",
+            "Dickens",
+            "Scunthorpe",
+        ] {
+            assert!(!filter.is_blocked(word), "{}", word);
+        }
+        assert!(filter.is_blocked("Grapecock"));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::Error;
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use super::{vowel_groups, WordBuilder, WordLength};
+
+/// Marks the start of a word in a context.
+const START: char = '\u{2}';
+/// Marks the end of a word as a transition.
+const END: char = '\u{3}';
+/// How many times to try for a word that isn't in the training set before settling for one that is.
+const NOVEL_ATTEMPTS: usize = 100;
+
+/// The characters that can follow a given context, with how often they did in the training set.
+#[derive(Debug, Clone)]
+struct Transitions {
+    chars: Vec<char>,
+    counts: Vec<u32>,
+    weights: WeightedIndex<u32>,
+}
+
+impl Transitions {
+    /// Samples the next character, only ending the word if `can_end` allows it. Returns `None` if nothing else can
+    /// follow.
+    fn sample<R: Rng + ?Sized>(&self, can_end: bool, rng: &mut R) -> Option<char> {
+        if can_end {
+            return Some(self.chars[self.weights.sample(rng)]);
+        }
+        let counts = self
+            .chars
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(c, n)| if *c == END { 0 } else { *n });
+        let weights = WeightedIndex::new(counts).ok()?;
+        Some(self.chars[weights.sample(rng)])
+    }
+}
+
+/// Builds words with a character-level Markov chain trained on a list of example words. Word boundaries are learned
+/// from the examples, so [`WordBuilder::build`] ends words where the training set tends to.
+#[derive(Debug, Clone)]
+pub struct MarkovBuilder {
+    order: usize,
+    transitions: HashMap<Vec<char>, Transitions>,
+    examples: HashSet<String>,
+    max_len: usize,
+}
+
+impl MarkovBuilder {
+    /// Trains a new builder on the given example words, where `order` is how many preceding characters each choice
+    /// depends on. Panics if the order is zero or there are no examples; see [`MarkovBuilder::try_new`] for a
+    /// fallible version.
+    pub fn new<S: AsRef<str>>(examples: &[S], order: usize) -> Self {
+        Self::try_new(examples, order).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Trains a new builder like [`MarkovBuilder::new`], returning [`Error::InvalidModel`] if the order is zero and
+    /// [`Error::EmptySampler`] if there are no non-empty examples.
+    pub fn try_new<S: AsRef<str>>(examples: &[S], order: usize) -> Result<Self, Error> {
+        if order == 0 {
+            return Err(Error::InvalidModel(
+                "Markov order must be at least 1".to_string(),
+            ));
+        }
+        let examples: HashSet<String> = examples
+            .iter()
+            .map(|e| e.as_ref().trim().to_lowercase())
+            .filter(|e| !e.is_empty())
+            .collect();
+        if examples.is_empty() {
+            return Err(Error::EmptySampler);
+        }
+
+        // Ordered maps keep training deterministic, so seeded generation is reproducible across runs.
+        let mut counts = BTreeMap::<Vec<char>, BTreeMap<char, u32>>::new();
+        for example in examples.iter() {
+            let mut context = vec![START; order];
+            for c in example.chars().chain(std::iter::once(END)) {
+                *counts
+                    .entry(context.clone())
+                    .or_default()
+                    .entry(c)
+                    .or_default() += 1;
+                context.remove(0);
+                context.push(c);
+            }
+        }
+
+        let transitions = counts
+            .into_iter()
+            .map(|(context, next)| {
+                let (chars, counts): (Vec<char>, Vec<u32>) = next.into_iter().unzip();
+                let weights = WeightedIndex::new(&counts).unwrap();
+                let transitions = Transitions {
+                    chars,
+                    counts,
+                    weights,
+                };
+                (context, transitions)
+            })
+            .collect();
+        let max_len = examples.iter().map(|e| e.chars().count()).max().unwrap() * 2;
+
+        Ok(Self {
+            order,
+            transitions,
+            examples,
+            max_len,
+        })
+    }
+
+    /// Returns the order of the chain.
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Walks the chain. Unbounded words end wherever the chain chooses to, while bounded words can only end once
+    /// they reach the given length.
+    fn walk<R: Rng + ?Sized>(&self, length: &WordLength, rng: &mut R) -> String {
+        let mut word = Vec::<char>::new();
+        let mut context = vec![START; self.order];
+        while word.len() < self.max_len {
+            let bounded = match length {
+                WordLength::None => false,
+                WordLength::Chars(i) if word.len() >= *i as usize => break,
+                WordLength::Syllables(i) if vowel_groups(&word) >= *i as usize => break,
+                _ => true,
+            };
+            let Some(transitions) = self.transitions.get(&context) else {
+                break;
+            };
+            match transitions.sample(!bounded, rng) {
+                Some(END) | None => break,
+                Some(c) => {
+                    word.push(c);
+                    context.remove(0);
+                    context.push(c);
+                }
+            }
+        }
+        word.into_iter().collect()
+    }
+}
+
+impl WordBuilder for MarkovBuilder {
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.build_length(WordLength::None, rng)
+    }
+
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        let mut word = self.walk(&length, rng);
+        for _ in 1..NOVEL_ATTEMPTS {
+            if !self.examples.contains(&word) {
+                break;
+            }
+            word = self.walk(&length, rng);
+        }
+        word
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: [&str; 12] = [
+        "Aldric", "Baldric", "Cedric", "Eldred", "Godric", "Harold", "Leofric", "Osric",
+        "Roderick", "Wulfric", "Edmund", "Oswald",
+    ];
+
+    #[test]
+    fn markov_test() {
+        let mut rng = rand::thread_rng();
+        let mb = MarkovBuilder::new(&NAMES, 2);
+        let names: Vec<String> = NAMES.iter().map(|n| n.to_lowercase()).collect();
+
+        for _ in 0..100 {
+            let word = mb.build(&mut rng);
+            assert!(!word.is_empty());
+            assert!(word.chars().count() <= mb.max_len, "{}", word);
+            // An order 2 chain only joins runs of three letters that appear in the examples.
+            assert!(names.iter().any(|n| n.starts_with(&word[..2])), "{}", word);
+            let chars: Vec<char> = word.chars().collect();
+            for run in chars.windows(3) {
+                let run: String = run.iter().collect();
+                assert!(names.iter().any(|n| n.contains(&run)), "{}", word);
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_markov_test() {
+        assert!(matches!(
+            MarkovBuilder::try_new(&NAMES, 0),
+            Err(Error::InvalidModel(_))
+        ));
+        assert!(matches!(
+            MarkovBuilder::try_new::<&str>(&[], 2),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            MarkovBuilder::try_new(&["", "  "], 2),
+            Err(Error::EmptySampler)
+        ));
+        assert_eq!(MarkovBuilder::try_new(&NAMES, 3).unwrap().order(), 3);
+    }
+
+    #[test]
+    fn seeded_markov_test() {
+        let mb = MarkovBuilder::new(&NAMES, 2);
+        let words: Vec<String> = (0..10).map(|seed| mb.build_seeded(seed)).collect();
+        let again: Vec<String> = (0..10)
+            .map(|seed| MarkovBuilder::new(&NAMES, 2).build_seeded(seed))
+            .collect();
+        assert_eq!(words, again);
+    }
+
+    #[test]
+    fn markov_length_test() {
+        let mut rng = rand::thread_rng();
+        let mb = MarkovBuilder::new(&NAMES, 1);
+
+        for _ in 0..100 {
+            let word = mb.build_length(WordLength::Chars(4), &mut rng);
+            assert!(word.len() <= 4, "{}", word);
+        }
+
+        // Every letter of these can be followed by another, so words always reach the length asked for.
+        let mb = MarkovBuilder::new(&["nana", "banana", "bana"], 1);
+        for i in 1..6 {
+            let word = mb.build_length(WordLength::Chars(i), &mut rng);
+            assert_eq!(word.len(), i as usize, "{}", word);
+            let word = mb.build_length(WordLength::Syllables(i), &mut rng);
+            assert_eq!(word.matches('a').count(), i as usize, "{}", word);
+            assert_eq!(
+                vowel_groups(&word.chars().collect::<Vec<_>>()),
+                i as usize,
+                "{}",
+                word
+            );
+        }
+    }
+}
@@ -1,5 +1,33 @@
-use rand::{distributions::WeightedIndex, prelude::Distribution, rngs::ThreadRng};
+use crate::VOWLES;
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::sync::OnceLock;
 
+mod affixes;
+pub use affixes::*;
+mod compound;
+pub use compound::*;
+mod constrained;
+pub use constrained::*;
+mod filter;
+pub use filter::*;
+mod markov;
+pub use markov::*;
+mod punctuated;
+pub use punctuated::*;
+mod syllables;
+pub use syllables::*;
+mod trigraphs;
+pub use trigraphs::*;
+
+#[cfg(feature = "nouns")]
+mod names;
+#[cfg(feature = "nouns")]
+pub use names::*;
+#[cfg(feature = "nouns")]
+mod places;
+#[cfg(feature = "nouns")]
+pub use places::*;
 #[cfg(feature = "nouns")]
 mod propper_nouns;
 #[cfg(feature = "nouns")]
@@ -26,29 +54,75 @@ pub enum WordLength {
 /// A trait to denote a type that can build words.
 pub trait WordBuilder {
     /// Builds a new word.
-    fn build(&self, rng: &mut ThreadRng) -> String {
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
         let i = rand_word_length(rng);
         self.build_length(i, rng)
     }
     /// Builds a new word of given length, using the provided rng. Only uses upper-case letters for propper nouns etc.
-    fn build_length(&self, length: WordLength, rng: &mut ThreadRng) -> String;
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String;
+    /// Builds a new word from the given seed. The same seed always builds the same word.
+    fn build_seeded(&self, seed: u64) -> String {
+        self.build(&mut seeded_rng(seed))
+    }
+    /// Builds a new word of given length from the given seed. The same seed always builds the same word.
+    fn build_length_seeded(&self, length: WordLength, seed: u64) -> String {
+        self.build_length(length, &mut seeded_rng(seed))
+    }
 }
 
-static mut WORD_LENGTH_WEIGHTS: Option<WeightedIndex<f32>> = None;
+static WORD_LENGTH_WEIGHTS: OnceLock<WeightedIndex<f32>> = OnceLock::new();
 
 /// Returns the weighted Indices of the average word length.
 pub fn word_length_weights() -> WeightedIndex<f32> {
-    unsafe {
-        if WORD_LENGTH_WEIGHTS.is_none() {
-            let weights = WeightedIndex::new(&WORD_LENGTH_FREQUENCY).unwrap();
-            WORD_LENGTH_WEIGHTS = Some(weights);
-        }
-        WORD_LENGTH_WEIGHTS.clone().unwrap()
-    }
+    WORD_LENGTH_WEIGHTS
+        .get_or_init(|| WeightedIndex::new(WORD_LENGTH_FREQUENCY).unwrap())
+        .clone()
 }
 
 /// A quick and dirty random number generator that uses word length frequencies of the english language.
-pub fn rand_word_length(rng: &mut ThreadRng) -> WordLength {
+pub fn rand_word_length<R: Rng + ?Sized>(rng: &mut R) -> WordLength {
     let i = word_length_weights().sample(rng) + 1;
-    return WordLength::Chars(i as u8);
+    WordLength::Chars(i as u8)
+}
+
+/// Returns a reproducible rng for the given seed. ChaCha8 is used as its output is stable across platforms and
+/// `rand` releases, unlike `StdRng`.
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// Approximates the number of syllables in a word by counting its groups of vowels. A 'y' counts as a vowel
+/// anywhere but the start of the word.
+pub fn syllable_count(word: &str) -> usize {
+    let chars: Vec<char> = word.chars().map(|c| c.to_ascii_lowercase()).collect();
+    vowel_groups(&chars)
+}
+
+/// Counts the groups of consecutive vowels in a lower-case sequence of characters.
+fn vowel_groups(chars: &[char]) -> usize {
+    let is_vowel = |(i, c): (usize, &char)| VOWLES.contains(c) || (*c == 'y' && i > 0);
+    let mut count = 0;
+    let mut previous = false;
+    for vowel in chars.iter().enumerate().map(is_vowel) {
+        if vowel && !previous {
+            count += 1;
+        }
+        previous = vowel;
+    }
+    count
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn syllable_count_test() {
+        assert_eq!(syllable_count("wizard"), 2);
+        assert_eq!(syllable_count("Yellow"), 2);
+        assert_eq!(syllable_count("rhythm"), 1);
+        assert_eq!(syllable_count("beautiful"), 3);
+        assert_eq!(syllable_count(""), 0);
+    }
 }
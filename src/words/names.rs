@@ -0,0 +1,229 @@
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+use super::{seeded_rng, Affixes, NounBuilder, WordBuilder, WordLength};
+
+/// How often first names have one, two and three syllables.
+const FIRST_NAME_SYLLABLES: [f32; 3] = [0.2, 0.55, 0.25];
+
+/// The default surname suffixes, with their weights.
+const SURNAME_SUFFIXES: [(&str, f32); 14] = [
+    ("son", 3.0),
+    ("ton", 2.0),
+    ("wood", 1.5),
+    ("smith", 1.0),
+    ("ford", 1.5),
+    ("ley", 2.0),
+    ("field", 1.0),
+    ("well", 1.0),
+    ("by", 1.0),
+    ("worth", 1.0),
+    ("ham", 1.0),
+    ("ridge", 0.5),
+    ("man", 1.0),
+    ("wright", 0.5),
+];
+
+/// Builds first names from a noun builder, favouring names of two syllables.
+#[derive(Debug, Clone, Default)]
+pub struct FirstNameBuilder {
+    nouns: NounBuilder,
+}
+
+impl FirstNameBuilder {
+    /// Builds a new first name builder using English letter frequencies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a first name builder that generates its names with the given noun builder.
+    pub fn from_nouns(nouns: NounBuilder) -> Self {
+        Self { nouns }
+    }
+}
+
+impl WordBuilder for FirstNameBuilder {
+    /// Builds a name of one to three syllables.
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let syllables = WeightedIndex::new(FIRST_NAME_SYLLABLES)
+            .unwrap()
+            .sample(rng)
+            + 1;
+        self.build_length(WordLength::Syllables(syllables as u8), rng)
+    }
+
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        self.nouns.build_length(length, rng)
+    }
+}
+
+/// Builds surnames by joining a generated stem to a suffix such as "-son" or "-wood".
+#[derive(Debug, Clone)]
+pub struct SurnameBuilder {
+    nouns: NounBuilder,
+    suffixes: Affixes,
+    suffix_chance: f64,
+}
+
+impl Default for SurnameBuilder {
+    fn default() -> Self {
+        Self {
+            nouns: NounBuilder::new(),
+            suffixes: Affixes::new(SURNAME_SUFFIXES),
+            suffix_chance: 0.8,
+        }
+    }
+}
+
+impl SurnameBuilder {
+    /// Builds a new surname builder using English letter frequencies and suffixes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a surname builder that generates its stems with the given noun builder.
+    pub fn from_nouns(nouns: NounBuilder) -> Self {
+        Self {
+            nouns,
+            ..Default::default()
+        }
+    }
+
+    /// Replaces the suffix table. Suffixes should be lower case and a single syllable.
+    pub fn suffixes(mut self, suffixes: Affixes) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+
+    /// Sets the probability that a surname is given a suffix. Panics if `chance` is not within `[0, 1]`.
+    pub fn suffix_chance(mut self, chance: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&chance),
+            "Chance must be within [0, 1], got {}",
+            chance
+        );
+        self.suffix_chance = chance;
+        self
+    }
+}
+
+impl WordBuilder for SurnameBuilder {
+    /// Builds a surname of two syllables.
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.build_length(WordLength::Syllables(2), rng)
+    }
+
+    /// Builds a surname of the given length. A suffix takes the place of the last syllable, so names of a single
+    /// syllable, or measured in characters, never have one.
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        if let WordLength::Syllables(i) = length {
+            if i > 1 && rng.gen_bool(self.suffix_chance) {
+                if let Some(suffix) = self.suffixes.sample(rng) {
+                    let stem = self.nouns.build_length(WordLength::Syllables(i - 1), rng);
+                    trace!(stem = %stem, suffix = %suffix, "joined surname");
+                    return stem + suffix;
+                }
+            }
+        }
+        self.nouns.build_length(length, rng)
+    }
+}
+
+/// Builds full names from a first name, optional middle names and a surname, such as "Aldric T. Thornwood".
+#[derive(Debug, Clone, Default)]
+pub struct FullNameBuilder<F: WordBuilder = FirstNameBuilder, S: WordBuilder = SurnameBuilder> {
+    first: F,
+    surname: S,
+    middle_names: usize,
+    middle_initials: bool,
+}
+
+impl FullNameBuilder {
+    /// Builds a new full name builder with English first names and surnames.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<F: WordBuilder, S: WordBuilder> FullNameBuilder<F, S> {
+    /// Builds a full name builder from the given first name and surname builders.
+    pub fn from_builders(first: F, surname: S) -> Self {
+        Self {
+            first,
+            surname,
+            middle_names: 0,
+            middle_initials: false,
+        }
+    }
+
+    /// Sets how many middle names each full name has. Middle names are built like first names.
+    pub fn middle_names(mut self, count: usize) -> Self {
+        self.middle_names = count;
+        self
+    }
+
+    /// Sets whether middle names are shortened to their initial, as in "Aldric T. Thornwood".
+    pub fn middle_initials(mut self, initials: bool) -> Self {
+        self.middle_initials = initials;
+        self
+    }
+
+    /// Builds a new full name.
+    pub fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let mut names = vec![self.first.build(rng)];
+        for _ in 0..self.middle_names {
+            let middle = self.first.build(rng);
+            if self.middle_initials {
+                names.extend(middle.chars().next().map(|c| format!("{}.", c)));
+            } else {
+                names.push(middle);
+            }
+        }
+        names.push(self.surname.build(rng));
+        names.join(" ")
+    }
+
+    /// Builds a new full name from the given seed. The same seed always builds the same name.
+    pub fn build_seeded(&self, seed: u64) -> String {
+        self.build(&mut seeded_rng(seed))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::syllable_count;
+
+    #[test]
+    fn surname_test() {
+        let mut rng = rand::thread_rng();
+        let sb = SurnameBuilder::new()
+            .suffixes(Affixes::new([("wood", 1.0)]))
+            .suffix_chance(1.0);
+
+        for _ in 0..100 {
+            let surname = sb.build_length(WordLength::Syllables(3), &mut rng);
+            assert!(surname.ends_with("wood"), "{}", surname);
+            assert_eq!(syllable_count(&surname), 3, "{}", surname);
+        }
+    }
+
+    #[test]
+    fn full_name_test() {
+        let mut rng = rand::thread_rng();
+        let fb = FullNameBuilder::new().middle_names(2).middle_initials(true);
+
+        for _ in 0..100 {
+            let name = fb.build(&mut rng);
+            let parts: Vec<&str> = name.split(' ').collect();
+            assert_eq!(parts.len(), 4, "{}", name);
+            assert!(parts[1].ends_with('.') && parts[2].len() == 2, "{}", name);
+            assert!(
+                parts.iter().all(|p| p.starts_with(char::is_uppercase)),
+                "{}",
+                name
+            );
+        }
+        assert_eq!(fb.build_seeded(9), fb.build_seeded(9));
+    }
+}
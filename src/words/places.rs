@@ -0,0 +1,209 @@
+use rand::Rng;
+
+use super::{Affixes, NounBuilder, WordBuilder, WordLength};
+
+/// The default place name prefixes, with their weights. Each includes the space that separates it from the name.
+const PLACE_PREFIXES: [(&str, f32); 8] = [
+    ("North ", 1.0),
+    ("South ", 1.0),
+    ("East ", 1.0),
+    ("West ", 1.0),
+    ("Upper ", 0.5),
+    ("Lower ", 0.5),
+    ("Great ", 0.5),
+    ("Little ", 0.5),
+];
+
+/// The default place name suffixes, with their weights.
+const PLACE_SUFFIXES: [(&str, f32); 14] = [
+    ("ton", 3.0),
+    ("ford", 2.0),
+    ("burgh", 1.0),
+    ("shire", 0.5),
+    ("haven", 1.0),
+    ("vale", 1.0),
+    ("wick", 1.5),
+    ("field", 1.0),
+    ("bury", 1.5),
+    ("port", 0.5),
+    ("mouth", 0.5),
+    ("ham", 2.0),
+    ("by", 1.0),
+    ("stead", 0.5),
+];
+
+/// The default features that follow a place name as a second word, with their weights.
+const PLACE_FEATURES: [(&str, f32); 8] = [
+    ("Crossing", 1.0),
+    ("Falls", 1.0),
+    ("Hollow", 1.0),
+    ("Bridge", 1.0),
+    ("Green", 1.0),
+    ("Heath", 0.5),
+    ("Cross", 0.5),
+    ("Landing", 0.5),
+];
+
+/// Builds place names by joining a generated stem to toponymic affixes, as in "Lower Ashford" or "Oakvale Crossing".
+/// Each affix table can be replaced, such as to theme names by region.
+#[derive(Debug, Clone)]
+pub struct PlaceNameBuilder {
+    nouns: NounBuilder,
+    prefixes: Affixes,
+    suffixes: Affixes,
+    features: Affixes,
+    prefix_chance: f64,
+    suffix_chance: f64,
+    feature_chance: f64,
+}
+
+impl Default for PlaceNameBuilder {
+    fn default() -> Self {
+        Self {
+            nouns: NounBuilder::new(),
+            prefixes: Affixes::new(PLACE_PREFIXES),
+            suffixes: Affixes::new(PLACE_SUFFIXES),
+            features: Affixes::new(PLACE_FEATURES),
+            prefix_chance: 0.15,
+            suffix_chance: 0.8,
+            feature_chance: 0.1,
+        }
+    }
+}
+
+impl PlaceNameBuilder {
+    /// Builds a new place name builder using English letter frequencies and affixes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a place name builder that generates its stems with the given noun builder.
+    pub fn from_nouns(nouns: NounBuilder) -> Self {
+        Self {
+            nouns,
+            ..Default::default()
+        }
+    }
+
+    /// Replaces the prefix table. Prefixes are placed directly before the name, so should end with a space if they
+    /// are separate words.
+    pub fn prefixes(mut self, prefixes: Affixes) -> Self {
+        self.prefixes = prefixes;
+        self
+    }
+
+    /// Replaces the suffix table. Suffixes should be lower case and a single syllable.
+    pub fn suffixes(mut self, suffixes: Affixes) -> Self {
+        self.suffixes = suffixes;
+        self
+    }
+
+    /// Replaces the table of features that can follow the name as a second word.
+    pub fn features(mut self, features: Affixes) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Sets the probability that a name is given a prefix. Panics if `chance` is not within `[0, 1]`.
+    pub fn prefix_chance(mut self, chance: f64) -> Self {
+        self.prefix_chance = checked_chance(chance);
+        self
+    }
+
+    /// Sets the probability that a name is given a suffix. Panics if `chance` is not within `[0, 1]`.
+    pub fn suffix_chance(mut self, chance: f64) -> Self {
+        self.suffix_chance = checked_chance(chance);
+        self
+    }
+
+    /// Sets the probability that a name is followed by a feature. Panics if `chance` is not within `[0, 1]`.
+    pub fn feature_chance(mut self, chance: f64) -> Self {
+        self.feature_chance = checked_chance(chance);
+        self
+    }
+
+    /// Takes an affix from the table with the given probability.
+    fn affix<'a, R: Rng + ?Sized>(
+        affixes: &'a Affixes,
+        chance: f64,
+        rng: &mut R,
+    ) -> Option<&'a str> {
+        if rng.gen_bool(chance) {
+            affixes.sample(rng)
+        } else {
+            None
+        }
+    }
+}
+
+/// Panics if the chance is not a probability.
+fn checked_chance(chance: f64) -> f64 {
+    assert!(
+        (0.0..=1.0).contains(&chance),
+        "Chance must be within [0, 1], got {}",
+        chance
+    );
+    chance
+}
+
+impl WordBuilder for PlaceNameBuilder {
+    /// Builds a place name whose main word has two syllables.
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        self.build_length(WordLength::Syllables(2), rng)
+    }
+
+    /// Builds a place name whose main word has the given length. A suffix takes the place of the last syllable, so
+    /// names of a single syllable, or measured in characters, never have one. Prefixes and features are not counted.
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        let mut name = match length {
+            WordLength::Syllables(i) if i > 1 => {
+                match Self::affix(&self.suffixes, self.suffix_chance, rng) {
+                    Some(suffix) => {
+                        self.nouns.build_length(WordLength::Syllables(i - 1), rng) + suffix
+                    }
+                    None => self.nouns.build_length(length, rng),
+                }
+            }
+            _ => self.nouns.build_length(length, rng),
+        };
+        if let Some(prefix) = Self::affix(&self.prefixes, self.prefix_chance, rng) {
+            name.insert_str(0, prefix);
+        }
+        if let Some(feature) = Self::affix(&self.features, self.feature_chance, rng) {
+            name.push(' ');
+            name.push_str(feature);
+        }
+        trace!(name = %name, "built place name");
+        name
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn place_name_test() {
+        let mut rng = rand::thread_rng();
+        let pb = PlaceNameBuilder::new()
+            .prefixes(Affixes::new([("Lower ", 1.0)]))
+            .suffixes(Affixes::new([("ford", 1.0)]))
+            .features(Affixes::new([("Crossing", 1.0)]))
+            .prefix_chance(1.0)
+            .suffix_chance(1.0)
+            .feature_chance(1.0);
+
+        for _ in 0..100 {
+            let name = pb.build(&mut rng);
+            assert!(name.starts_with("Lower "), "{}", name);
+            assert!(name.ends_with("ford Crossing"), "{}", name);
+        }
+
+        let plain = PlaceNameBuilder::new()
+            .prefix_chance(0.0)
+            .suffix_chance(0.0)
+            .feature_chance(0.0);
+        assert!(!plain.build(&mut rng).contains(' '));
+    }
+}
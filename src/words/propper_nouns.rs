@@ -1,14 +1,26 @@
+use crate::analysis::{analyze, Analysis};
 use crate::VOWLES;
-use crate::{Digraph, Letter, NGramSampler};
-use rand::{rngs::ThreadRng, Rng};
+use crate::{AlphabetType, Digraph, Error, Letter, NGramSampler};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "fs")]
+use std::fs;
+use std::io::Read;
+#[cfg(feature = "fs")]
+use std::path::Path;
+use std::sync::OnceLock;
 
-use super::{WordLength, WordBuilder};
+use super::{vowel_groups, WordBuilder, WordLength};
 
 /// Constructs propper nouns.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct NounBuilder {
     digraphs: NGramSampler<Digraph>,
     letters: NGramSampler<Letter>,
+    #[serde(default)]
+    initials: Option<NGramSampler<Letter>>,
+    #[serde(default)]
+    finals: Option<NGramSampler<Letter>>,
 }
 
 impl NounBuilder {
@@ -17,53 +29,275 @@ impl NounBuilder {
         Self {
             digraphs: Default::default(),
             letters: Default::default(),
+            initials: None,
+            finals: None,
         }
     }
-}
 
-impl WordBuilder for NounBuilder {
-    fn build_length(&self, length: WordLength, rng: &mut ThreadRng) -> String {
-        let len: usize = match length {
-            WordLength::Chars(i) => i as usize,
-            WordLength::Syllables(i) => i as usize,
-            WordLength::None => 7,
-        }.max(3);
-
-        let mut word = Vec::<char>::new();
-        while word.len() < len {
-            if rng.gen() {
-                // Use a letter
-                let l = word.len();
-                if l > 0 {
-                    let last = word[l - 1];
-                    if VOWLES.contains(&last) {
-                        let new: char = self.letters.sample_consonants(rng).into();
-                        if new != last {
-                            word.push(new);
-                        }
+    /// Builds a noun builder from the given letter and digraph samplers.
+    pub fn from_samplers(letters: NGramSampler<Letter>, digraphs: NGramSampler<Digraph>) -> Self {
+        Self {
+            digraphs,
+            letters,
+            initials: None,
+            finals: None,
+        }
+    }
+
+    /// Returns the builder with positional letter tables. Words start with a letter drawn from `initials`, and a
+    /// last letter that never ends a word in `finals` is swapped for one that does.
+    pub fn with_positions(
+        mut self,
+        initials: NGramSampler<Letter>,
+        finals: NGramSampler<Letter>,
+    ) -> Self {
+        self.initials = Some(initials);
+        self.finals = Some(finals);
+        self
+    }
+
+    /// Trains a noun builder on the letter and digraph frequencies of the given text, so that it generates words in
+    /// the same style, including which letters its words start and end with. Returns [`Error::EmptySampler`] if the
+    /// text has no letters, or no words of more than one letter to take digraphs from, and [`Error::NoVowels`] or
+    /// [`Error::NoConsonants`] if it lacks either kind of letter.
+    pub fn from_text(text: &str) -> Result<Self, Error> {
+        Self::from_analysis(analyze(text))
+    }
+
+    /// Builds a noun builder from the results of analyzing some text, such as a smoothed
+    /// [`analyze_smoothed`](crate::analysis::analyze_smoothed) for small corpora.
+    pub fn from_analysis(analysis: Analysis) -> Result<Self, Error> {
+        let letters = NGramSampler::try_new(analysis.letters)?;
+        if letters.sample_set().iter().all(|l| l.is_consonant()) {
+            return Err(Error::NoVowels);
+        }
+        if letters.sample_set().iter().all(|l| l.is_vowel()) {
+            return Err(Error::NoConsonants);
+        }
+        Ok(
+            Self::from_samplers(letters, NGramSampler::try_new(analysis.digraphs)?).with_positions(
+                NGramSampler::try_new(analysis.initials)?,
+                NGramSampler::try_new(analysis.finals)?,
+            ),
+        )
+    }
+
+    /// Trains a noun builder on all of the text read from the given reader. See [`NounBuilder::from_text`].
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Self::from_text(&text)
+    }
+
+    /// Serializes the builder, including its trained samplers, to pretty RON.
+    pub fn to_ron(&self) -> Result<String, Error> {
+        Ok(ron::ser::to_string_pretty(
+            self,
+            ron::ser::PrettyConfig::new(),
+        )?)
+    }
+
+    /// Parses a builder written by [`NounBuilder::to_ron`], such as one embedded with `include_str!`. The samplers
+    /// are validated, but no size limits are applied, so only parse builders from trusted sources.
+    pub fn from_ron(s: &str) -> Result<Self, Error> {
+        Ok(ron::from_str(s).map_err(|e| e.code)?)
+    }
+
+    /// Writes the builder, including its trained samplers, to the given path as RON.
+    #[cfg(feature = "fs")]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        fs::write(path, self.to_ron()?)?;
+        Ok(())
+    }
+
+    /// Loads a builder written by [`NounBuilder::save`]. Like [`NounBuilder::from_ron`], this is for trusted files.
+    #[cfg(feature = "fs")]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_ron(&fs::read_to_string(path)?)
+    }
+
+    /// Builds a noun builder trained on Old Norse names.
+    pub fn norse() -> Self {
+        static NORSE: OnceLock<NounBuilder> = OnceLock::new();
+        NORSE
+            .get_or_init(|| Self::preset(include_str!("../norse.txt")))
+            .clone()
+    }
+
+    /// Builds a noun builder trained on Latin names.
+    pub fn latinate() -> Self {
+        static LATINATE: OnceLock<NounBuilder> = OnceLock::new();
+        LATINATE
+            .get_or_init(|| Self::preset(include_str!("../latinate.txt")))
+            .clone()
+    }
+
+    /// Builds a noun builder trained on Old English names.
+    pub fn old_english() -> Self {
+        static OLD_ENGLISH: OnceLock<NounBuilder> = OnceLock::new();
+        OLD_ENGLISH
+            .get_or_init(|| Self::preset(include_str!("../old_english.txt")))
+            .clone()
+    }
+
+    /// Interpolates between the letter, digraph and positional frequencies of this builder and another. See
+    /// [`NGramSampler::blend`]. A positional table only one of the builders has is kept as it is.
+    pub fn blend(&self, other: &Self, t: f32) -> Self {
+        let positions =
+            |a: &Option<NGramSampler<Letter>>, b: &Option<NGramSampler<Letter>>| match (a, b) {
+                (Some(a), Some(b)) => Some(a.blend(b, t)),
+                (a, b) => a.clone().or_else(|| b.clone()),
+            };
+        Self {
+            letters: self.letters.blend(&other.letters, t),
+            digraphs: self.digraphs.blend(&other.digraphs, t),
+            initials: positions(&self.initials, &other.initials),
+            finals: positions(&self.finals, &other.finals),
+        }
+    }
+
+    /// Trains a preset on an embedded list of names, which is known to be usable.
+    fn preset(text: &str) -> Self {
+        match Self::from_text(text) {
+            Ok(builder) => builder,
+            Err(e) => panic!("Failed to load preset: {}", e),
+        }
+    }
+
+    /// Builds a noun that starts with the given letter, such as for alliterative names ("Brave Boris").
+    pub fn build_starting_with<R: Rng + ?Sized>(
+        &self,
+        initial: char,
+        length: WordLength,
+        rng: &mut R,
+    ) -> String {
+        self.build_from(initial.to_lowercase().collect(), length, rng)
+    }
+
+    /// Extends the given start of a word until it reaches the length, then finishes it off.
+    fn build_from<R: Rng + ?Sized>(
+        &self,
+        mut word: Vec<char>,
+        length: WordLength,
+        rng: &mut R,
+    ) -> String {
+        match length {
+            WordLength::Syllables(i) => {
+                // A single letter or digraph never adds more than one vowel group, so this can't overshoot.
+                while vowel_groups(&word) < (i as usize).max(1) {
+                    self.extend(&mut word, rng);
+                }
+                while word.len() < 3 {
+                    // Padding with a 'y' could add another syllable.
+                    let new: char = self.letters.sample_consonants(rng).into();
+                    if new != 'y' {
+                        trace!(letter = %new, "padded short word");
+                        word.push(new);
+                    }
+                }
+            }
+            WordLength::Chars(i) => {
+                while word.len() < (i as usize).max(3) {
+                    self.extend(&mut word, rng);
+                }
+            }
+            WordLength::None => {
+                while word.len() < 7 {
+                    self.extend(&mut word, rng);
+                }
+            }
+        }
+
+        self.finish(&mut word, rng);
+        let first = word[0].to_ascii_uppercase();
+        word[0] = first;
+        let word: String = word.into_iter().collect();
+        trace!(word = %word, ?length, "built noun");
+        word
+    }
+
+    /// Extends the word by either a single letter or a whole digraph.
+    fn extend<R: Rng + ?Sized>(&self, word: &mut Vec<char>, rng: &mut R) {
+        if let (true, Some(initials)) = (word.is_empty(), &self.initials) {
+            let new: char = initials.sample(rng).into();
+            trace!(letter = %new, "sampled initial letter");
+            word.push(new);
+        } else if rng.gen() {
+            // Use a letter
+            let l = word.len();
+            if l > 0 {
+                let last = word[l - 1];
+                if VOWLES.contains(&last) {
+                    let new: char = self.letters.sample_consonants(rng).into();
+                    if new != last {
+                        trace!(letter = %new, "sampled consonant after vowel");
+                        word.push(new);
                     } else {
-                        word.push(self.letters.sample_vowels(rng).into());
+                        trace!(letter = %new, "rejected repeated consonant");
                     }
                 } else {
-                    word.push(self.letters.sample(rng).into());
+                    let new: char = self.letters.sample_vowels(rng).into();
+                    trace!(letter = %new, "sampled vowel after consonant");
+                    word.push(new);
                 }
             } else {
-                word.append(&mut self.digraphs.sample(rng).chars.to_vec());
+                let new: char = self.letters.sample(rng).into();
+                trace!(letter = %new, "sampled first letter");
+                word.push(new);
             }
+        } else if let Some(digraph) = word
+            .last()
+            .and_then(|last| self.digraphs.sample_following(*last, rng))
+        {
+            // Continue from the last letter, so the join between them is a real digraph too.
+            trace!(digraph = %digraph, "sampled following digraph");
+            word.push(digraph.chars[1]);
+        } else {
+            let digraph = self.digraphs.sample(rng);
+            trace!(digraph = %digraph, "sampled digraph");
+            word.extend_from_slice(&digraph.chars);
         }
+    }
 
-        let first = word[0].to_ascii_uppercase();
-        word[0] = first;
-        return word.into_iter().collect();
+    /// Swaps the last letter for one of the same kind that can end a word, if it can't already. The swap is skipped
+    /// if it would change the number of syllables.
+    fn finish<R: Rng + ?Sized>(&self, word: &mut [char], rng: &mut R) {
+        let Some(finals) = &self.finals else {
+            return;
+        };
+        let l = word.len();
+        let last = word[l - 1];
+        if finals.sample_set().iter().any(|f| f.chars[0] == last) {
+            return;
+        }
+        let new = if VOWLES.contains(&last) {
+            finals.try_sample_vowels(rng)
+        } else {
+            finals.try_sample_consonants(rng)
+        };
+        if let Ok(new) = new {
+            let groups = vowel_groups(word);
+            word[l - 1] = new.into();
+            if vowel_groups(word) == groups {
+                trace!(letter = %word[l - 1], "swapped final letter");
+            } else {
+                word[l - 1] = last;
+            }
+        }
     }
+}
 
-    
+impl WordBuilder for NounBuilder {
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        self.build_from(Vec::new(), length, rng)
+    }
 }
 
 // Tests
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::words::syllable_count;
 
     #[test]
     fn propper_noun_test() {
@@ -75,4 +309,143 @@ mod tests {
             println!("{}: {}", i, noun);
         }
     }
+
+    #[test]
+    fn trained_noun_test() {
+        let mut rng = rand::thread_rng();
+        let names = "Ragnar Sigurd Bjorn Ivar Ulf Gunnar Halfdan Thorvald Eirik Sven Torstein";
+        let nb = NounBuilder::from_reader(names.as_bytes()).unwrap();
+
+        for _ in 0..100 {
+            let noun = nb.build(&mut rng).to_lowercase();
+            assert!(
+                noun.chars().all(|c| names.to_lowercase().contains(c)),
+                "{}",
+                noun
+            );
+        }
+    }
+
+    #[test]
+    fn untrainable_text_test() {
+        assert!(matches!(
+            NounBuilder::from_text(""),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            NounBuilder::from_text("a b c, 1 2 3"),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            NounBuilder::from_text("bbb ccc ddd"),
+            Err(Error::NoVowels)
+        ));
+        assert!(matches!(
+            NounBuilder::from_text("aaa eee ooo"),
+            Err(Error::NoConsonants)
+        ));
+        assert!(matches!(
+            NounBuilder::from_reader("".as_bytes()),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            NounBuilder::from_reader(&[0xff, 0xfe][..]),
+            Err(Error::Io(_))
+        ));
+    }
+
+    #[test]
+    fn positional_test() {
+        let mut rng = rand::thread_rng();
+        let nb = NounBuilder::from_text("Anna Marian Julian Nina Elena Dorian Lena Ronan").unwrap();
+
+        for _ in 0..100 {
+            let noun = nb.build(&mut rng).to_lowercase();
+            assert!("amjnedlr".contains(&noun[..1]), "{}", noun);
+            assert!(noun.ends_with('a') || noun.ends_with('n'), "{}", noun);
+        }
+    }
+
+    #[test]
+    fn blend_test() {
+        let mut rng = rand::thread_rng();
+        let a = NounBuilder::from_text("Anna Nina Elena Lena").unwrap();
+        let b = NounBuilder::from_text("Marian Julian Dorian Ronan").unwrap();
+        let blended = a.blend(&b, 0.5);
+        assert!(blended.initials.is_some() && blended.finals.is_some());
+
+        for _ in 0..100 {
+            let noun = blended.build(&mut rng).to_lowercase();
+            assert!("aenlmjdr".contains(&noun[..1]), "{}", noun);
+            assert!(noun.ends_with('a') || noun.ends_with('n'), "{}", noun);
+        }
+
+        let untrained = NounBuilder::new().blend(&a, 0.5);
+        assert!(untrained.initials.is_some() && untrained.finals.is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "fs")]
+    fn save_test() {
+        let nb = NounBuilder::norse();
+        let path = std::env::temp_dir().join("engish_noun_save_test.ron");
+        nb.save(&path).unwrap();
+        let back = NounBuilder::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(back.build_seeded(3), nb.build_seeded(3));
+    }
+
+    #[test]
+    fn preset_test() {
+        let mut rng = rand::thread_rng();
+        let presets = [
+            (NounBuilder::norse(), include_str!("../norse.txt")),
+            (NounBuilder::latinate(), include_str!("../latinate.txt")),
+            (
+                NounBuilder::old_english(),
+                include_str!("../old_english.txt"),
+            ),
+        ];
+        for (nb, names) in presets {
+            let noun = nb.build(&mut rng).to_lowercase();
+            assert!(
+                noun.chars().all(|c| names.to_lowercase().contains(c)),
+                "{}",
+                noun
+            );
+        }
+    }
+
+    #[test]
+    fn seeded_noun_test() {
+        let nb = NounBuilder::new();
+        assert_eq!(nb.build_seeded(42), NounBuilder::new().build_seeded(42));
+        assert_eq!(
+            nb.build_length_seeded(WordLength::Syllables(3), 7),
+            nb.build_length_seeded(WordLength::Syllables(3), 7)
+        );
+    }
+
+    #[test]
+    fn starting_with_test() {
+        let mut rng = rand::thread_rng();
+        let nb = NounBuilder::new();
+
+        for i in (1..4).cycle().take(30) {
+            let noun = nb.build_starting_with('B', WordLength::Syllables(i), &mut rng);
+            assert!(noun.starts_with('B'), "{}", noun);
+            assert_eq!(syllable_count(&noun), i as usize, "{}", noun);
+        }
+    }
+
+    #[test]
+    fn syllable_length_test() {
+        let mut rng = rand::thread_rng();
+        let nb = NounBuilder::new();
+
+        for i in (1..5).cycle().take(100) {
+            let noun = nb.build_length(WordLength::Syllables(i), &mut rng);
+            assert_eq!(syllable_count(&noun), i as usize, "{}", noun);
+        }
+    }
 }
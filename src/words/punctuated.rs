@@ -0,0 +1,126 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::{WordBuilder, WordLength};
+
+/// Wraps another builder, occasionally inserting a punctuation mark such as an apostrophe or hyphen into its words
+/// ("Kal'dor", "Ash-Varen"). A mark is never placed first or last, never next to another mark, and at most one is
+/// added to each word. Marks are not counted towards the requested [`WordLength`].
+#[derive(Debug, Clone)]
+pub struct PunctuatedBuilder<B: WordBuilder> {
+    inner: B,
+    marks: Vec<char>,
+    chance: f64,
+    capitalize: bool,
+}
+
+impl<B: WordBuilder> PunctuatedBuilder<B> {
+    /// Wraps the given builder, adding an apostrophe or hyphen to a quarter of its words.
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            marks: vec!['\'', '-'],
+            chance: 0.25,
+            capitalize: false,
+        }
+    }
+
+    /// Sets which marks can be inserted. An empty list disables punctuation.
+    pub fn marks(mut self, marks: &[char]) -> Self {
+        self.marks = marks.to_vec();
+        self
+    }
+
+    /// Sets the probability that a word is given a mark. Panics if `chance` is not within `[0, 1]`.
+    pub fn chance(mut self, chance: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&chance),
+            "Chance must be within [0, 1], got {}",
+            chance
+        );
+        self.chance = chance;
+        self
+    }
+
+    /// Sets whether the letter following a mark is capitalized.
+    pub fn capitalize(mut self, capitalize: bool) -> Self {
+        self.capitalize = capitalize;
+        self
+    }
+
+    /// Returns the wrapped builder.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Inserts a mark into the word, if the dice allow it and the word has room for one.
+    fn punctuate<R: Rng + ?Sized>(&self, word: String, rng: &mut R) -> String {
+        let mut chars: Vec<char> = word.chars().collect();
+        if chars
+            .iter()
+            .any(|c| self.marks.contains(c) || !c.is_alphabetic())
+        {
+            return word;
+        }
+        let Some(&mark) = self.marks.choose(rng) else {
+            return word;
+        };
+        if chars.len() < 2 || !rng.gen_bool(self.chance) {
+            return word;
+        }
+
+        let i = rng.gen_range(1..chars.len());
+        if self.capitalize {
+            chars[i] = chars[i].to_ascii_uppercase();
+        }
+        chars.insert(i, mark);
+        let word: String = chars.into_iter().collect();
+        trace!(word = %word, mark = %mark, "punctuated word");
+        word
+    }
+}
+
+impl<B: WordBuilder> WordBuilder for PunctuatedBuilder<B> {
+    fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let word = self.inner.build(rng);
+        self.punctuate(word, rng)
+    }
+
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        let word = self.inner.build_length(length, rng);
+        self.punctuate(word, rng)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::SyllableBuilder;
+
+    #[test]
+    fn punctuated_test() {
+        let mut rng = rand::thread_rng();
+        let pb = PunctuatedBuilder::new(SyllableBuilder::default())
+            .chance(1.0)
+            .capitalize(true);
+
+        for _ in 0..100 {
+            let word = pb.build_length(WordLength::Chars(6), &mut rng);
+            let marks: Vec<usize> = word
+                .char_indices()
+                .filter(|(_, c)| *c == '\'' || *c == '-')
+                .map(|(i, _)| i)
+                .collect();
+            assert_eq!(marks.len(), 1, "{}", word);
+            assert!(marks[0] > 0 && marks[0] < word.len() - 1, "{}", word);
+            assert!(
+                word[marks[0] + 1..].starts_with(char::is_uppercase),
+                "{}",
+                word
+            );
+        }
+
+        let plain = PunctuatedBuilder::new(SyllableBuilder::default()).marks(&[]);
+        assert!(plain.build(&mut rng).chars().all(char::is_alphabetic));
+    }
+}
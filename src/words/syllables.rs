@@ -0,0 +1,265 @@
+use crate::util::syllabify;
+use crate::Error;
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, sync::OnceLock};
+
+use super::{WordBuilder, WordLength};
+
+/// A phonotactic model of syllables: the onsets, nuclei and codas they are built from, with their frequencies.
+/// Syllables without an onset or coda are represented by an empty string.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SyllableModel {
+    /// The consonants that can start a syllable.
+    pub onsets: Vec<(String, f32)>,
+    /// The vowels at the core of a syllable.
+    pub nuclei: Vec<(String, f32)>,
+    /// The consonants that can end a syllable.
+    pub codas: Vec<(String, f32)>,
+}
+
+impl SyllableModel {
+    /// Trains a model on the syllables of every word in the given text.
+    pub fn from_text(text: &str) -> Self {
+        let mut onsets = BTreeMap::<String, usize>::new();
+        let mut nuclei = BTreeMap::<String, usize>::new();
+        let mut codas = BTreeMap::<String, usize>::new();
+
+        let words = text
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| !w.is_empty());
+        for word in words {
+            let word = word.to_lowercase();
+            for syllable in syllabify(&word) {
+                let (onset, nucleus, coda) = split_syllable(syllable);
+                // A syllable with no vowels is just a fragment, and has no place in the model.
+                if nucleus.is_empty() {
+                    continue;
+                }
+                *onsets.entry(onset.to_string()).or_default() += 1;
+                *nuclei.entry(nucleus.to_string()).or_default() += 1;
+                *codas.entry(coda.to_string()).or_default() += 1;
+            }
+        }
+
+        Self {
+            onsets: frequencies(onsets),
+            nuclei: frequencies(nuclei),
+            codas: frequencies(codas),
+        }
+    }
+}
+
+impl Default for SyllableModel {
+    fn default() -> Self {
+        static ENGLISH: OnceLock<SyllableModel> = OnceLock::new();
+        ENGLISH
+            .get_or_init(|| match ron::from_str(include_str!("../syllables.ron")) {
+                Ok(model) => model,
+                Err(e) => panic!("Failed to load config: {}", e),
+            })
+            .clone()
+    }
+}
+
+/// Splits a syllable into its leading consonants, vowels and trailing letters.
+fn split_syllable(syllable: &str) -> (&str, &str, &str) {
+    let is_vowel = |(i, c): &(usize, char)| "aeiou".contains(*c) || (*c == 'y' && *i > 0);
+    let start = syllable
+        .char_indices()
+        .find(is_vowel)
+        .map_or(syllable.len(), |(i, _)| i);
+    let end = syllable[start..]
+        .char_indices()
+        .map(|(i, c)| (start + i, c))
+        .find(|ic| !is_vowel(ic))
+        .map_or(syllable.len(), |(i, _)| i);
+    (&syllable[..start], &syllable[start..end], &syllable[end..])
+}
+
+/// Converts a table of counts into relative frequencies.
+fn frequencies(counts: BTreeMap<String, usize>) -> Vec<(String, f32)> {
+    let total: usize = counts.values().sum();
+    counts
+        .into_iter()
+        .map(|(part, count)| (part, count as f32 / total as f32))
+        .collect()
+}
+
+/// Builds words one syllable at a time from a [`SyllableModel`], which tends to be more pronounceable than walking
+/// letters and digraphs.
+#[derive(Debug, Clone)]
+pub struct SyllableBuilder {
+    model: SyllableModel,
+    onsets: WeightedIndex<f32>,
+    nuclei: WeightedIndex<f32>,
+    codas: WeightedIndex<f32>,
+}
+
+impl SyllableBuilder {
+    /// Builds a new syllable builder from the given model. Panics if the model can't be sampled; see
+    /// [`SyllableBuilder::try_new`] for a fallible version.
+    pub fn new(model: SyllableModel) -> Self {
+        Self::try_new(model).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds a new syllable builder from the given model, returning [`Error::EmptySampler`] if any part of the
+    /// model is empty, and [`Error::InvalidModel`] if a part's weights are negative, non-finite or all zero.
+    pub fn try_new(model: SyllableModel) -> Result<Self, Error> {
+        let weights = |name: &str, parts: &[(String, f32)]| {
+            if parts.is_empty() {
+                return Err(Error::EmptySampler);
+            }
+            if !parts.iter().map(|(_, f)| *f).sum::<f32>().is_finite() {
+                return Err(Error::InvalidModel(format!(
+                    "total {} weight is not finite",
+                    name
+                )));
+            }
+            WeightedIndex::new(parts.iter().map(|(_, f)| *f))
+                .map_err(|e| Error::InvalidModel(format!("{} weights: {}", name, e)))
+        };
+        Ok(Self {
+            onsets: weights("onset", &model.onsets)?,
+            nuclei: weights("nucleus", &model.nuclei)?,
+            codas: weights("coda", &model.codas)?,
+            model,
+        })
+    }
+
+    /// Returns the model this builder samples from.
+    pub fn model(&self) -> &SyllableModel {
+        &self.model
+    }
+
+    /// Samples a single syllable.
+    pub fn syllable<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let onset = &self.model.onsets[self.onsets.sample(rng)].0;
+        let nucleus = &self.model.nuclei[self.nuclei.sample(rng)].0;
+        let coda = &self.model.codas[self.codas.sample(rng)].0;
+        trace!(onset, nucleus, coda, "sampled syllable");
+        format!("{}{}{}", onset, nucleus, coda)
+    }
+}
+
+impl Default for SyllableBuilder {
+    fn default() -> Self {
+        Self::new(SyllableModel::default())
+    }
+}
+
+/// The most syllables built per letter asked for before the builder stops trying to reach a character length.
+const MAX_SYLLABLES_PER_LETTER: usize = 8;
+
+impl WordBuilder for SyllableBuilder {
+    /// Builds a word of whole syllables. Character lengths are treated as a minimum, as syllables are never cut short.
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        let mut word = String::new();
+        match length {
+            WordLength::Syllables(i) => {
+                for _ in 0..i.max(1) {
+                    word.push_str(&self.syllable(rng));
+                }
+            }
+            WordLength::Chars(i) => {
+                // Models whose parts are empty strings can build empty syllables, so give up after a few per letter.
+                let chars = i.max(1) as usize;
+                let mut syllables = 0;
+                while word.chars().count() < chars && syllables < chars * MAX_SYLLABLES_PER_LETTER {
+                    word.push_str(&self.syllable(rng));
+                    syllables += 1;
+                }
+            }
+            WordLength::None => {
+                for _ in 0..2 {
+                    word.push_str(&self.syllable(rng));
+                }
+            }
+        }
+        word
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::vowel_groups;
+
+    #[test]
+    fn syllable_builder_test() {
+        let mut rng = rand::thread_rng();
+        let sb = SyllableBuilder::default();
+
+        let model = sb.model();
+        let letters: String = [&model.onsets, &model.nuclei, &model.codas]
+            .iter()
+            .flat_map(|parts| parts.iter().map(|(p, _)| p.as_str()))
+            .collect();
+        for _ in 0..100 {
+            let word = sb.build_length(WordLength::Syllables(2), &mut rng);
+            assert!(word.chars().count() >= 2, "{}", word);
+            assert!(word.chars().all(|c| letters.contains(c)), "{}", word);
+        }
+
+        // With an onset on every syllable, each nucleus is its own vowel group.
+        let parts = |parts: &[&str]| parts.iter().map(|p| (p.to_string(), 1.0)).collect();
+        let sb = SyllableBuilder::new(SyllableModel {
+            onsets: parts(&["b", "tr"]),
+            nuclei: parts(&["a", "oo"]),
+            codas: parts(&["", "n"]),
+        });
+        for i in 1..5 {
+            let word = sb.build_length(WordLength::Syllables(i), &mut rng);
+            let chars: Vec<char> = word.chars().collect();
+            assert_eq!(vowel_groups(&chars), i as usize, "{}", word);
+            assert!(chars.iter().all(|c| "btraon".contains(*c)), "{}", word);
+        }
+    }
+
+    #[test]
+    fn invalid_model_test() {
+        let mut rng = rand::thread_rng();
+        let parts =
+            |parts: &[(&str, f32)]| parts.iter().map(|(p, f)| (p.to_string(), *f)).collect();
+        let model = |nuclei: &[(&str, f32)]| SyllableModel {
+            onsets: parts(&[("b", 1.0)]),
+            nuclei: parts(nuclei),
+            codas: parts(&[("", 1.0)]),
+        };
+        assert!(matches!(
+            SyllableBuilder::try_new(SyllableModel::from_text("")),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            SyllableBuilder::try_new(model(&[("a", -1.0)])),
+            Err(Error::InvalidModel(_))
+        ));
+        assert!(matches!(
+            SyllableBuilder::try_new(model(&[("a", 0.0)])),
+            Err(Error::InvalidModel(_))
+        ));
+        assert!(matches!(
+            SyllableBuilder::try_new(model(&[("a", 3e38), ("e", 3e38)])),
+            Err(Error::InvalidModel(_))
+        ));
+
+        // A model of empty parts can't loop forever looking for letters.
+        let empty = SyllableBuilder::new(SyllableModel {
+            onsets: parts(&[("", 1.0)]),
+            nuclei: parts(&[("", 1.0)]),
+            codas: parts(&[("", 1.0)]),
+        });
+        assert_eq!(empty.build_length(WordLength::Chars(4), &mut rng), "");
+    }
+
+    #[test]
+    fn syllable_model_test() {
+        let model = SyllableModel::from_text("silver wizard, the table");
+        let part = |parts: &[(String, f32)], s: &str| parts.iter().any(|(p, _)| p == s);
+        assert!(part(&model.onsets, "bl"));
+        assert!(part(&model.onsets, ""));
+        assert!(part(&model.nuclei, "e"));
+        assert!(part(&model.codas, "rd"));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::analysis::{analyze, Analysis};
+use crate::{AlphabetType, Digraph, Error, Letter, NGramSampler, Trigraph};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{vowel_groups, WordBuilder, WordLength};
+
+/// Builds words letter by letter, choosing each from the trigraphs that follow the previous two letters. When no
+/// trigraph fits, it backs off to the digraphs that follow the previous letter, and then to the letter frequencies.
+///
+/// No English trigraph table is bundled, so there's no `Default`: train one on a corpus with
+/// [`TrigraphBuilder::from_text`], or load saved samplers and use [`TrigraphBuilder::from_samplers`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TrigraphBuilder {
+    trigraphs: NGramSampler<Trigraph>,
+    digraphs: NGramSampler<Digraph>,
+    letters: NGramSampler<Letter>,
+}
+
+impl TrigraphBuilder {
+    /// Builds a trigraph builder from the given samplers.
+    pub fn from_samplers(
+        letters: NGramSampler<Letter>,
+        digraphs: NGramSampler<Digraph>,
+        trigraphs: NGramSampler<Trigraph>,
+    ) -> Self {
+        Self {
+            trigraphs,
+            digraphs,
+            letters,
+        }
+    }
+
+    /// Trains a trigraph builder on the n-gram frequencies of the given text. Returns [`Error::EmptySampler`] if the
+    /// text has no word of three letters or more, and [`Error::NoVowels`] if it has no vowels.
+    pub fn from_text(text: &str) -> Result<Self, Error> {
+        Self::from_analysis(analyze(text))
+    }
+
+    /// Builds a trigraph builder from the results of analyzing some text, such as a smoothed
+    /// [`analyze_smoothed`](crate::analysis::analyze_smoothed) for small corpora.
+    pub fn from_analysis(analysis: Analysis) -> Result<Self, Error> {
+        let letters = NGramSampler::try_new(analysis.letters)?;
+        if letters.sample_set().iter().all(|l| l.is_consonant()) {
+            return Err(Error::NoVowels);
+        }
+        Ok(Self::from_samplers(
+            letters,
+            NGramSampler::try_new(analysis.digraphs)?,
+            NGramSampler::try_new(analysis.trigraphs)?,
+        ))
+    }
+
+    /// Samples the letter to follow the word so far, backing off to shorter n-grams when longer ones don't fit.
+    fn next<R: Rng + ?Sized>(&self, word: &[char], rng: &mut R) -> char {
+        let l = word.len();
+        if l >= 2 {
+            if let Some(trigraph) = self.trigraphs.sample_given(&word[l - 2..], rng) {
+                trace!(trigraph = %trigraph, "sampled trigraph");
+                return trigraph.chars[2];
+            }
+        }
+        if l >= 1 {
+            if let Some(digraph) = self.digraphs.sample_given(&word[l - 1..], rng) {
+                trace!(digraph = %digraph, "backed off to digraph");
+                return digraph.chars[1];
+            }
+        }
+        let letter = self.letters.sample(rng);
+        trace!(letter = %letter, "backed off to letter");
+        letter.into()
+    }
+}
+
+/// The most letters a word is given per syllable asked for before the builder stops looking for more vowels.
+const MAX_SYLLABLE_LETTERS: usize = 8;
+
+impl WordBuilder for TrigraphBuilder {
+    fn build_length<R: Rng + ?Sized>(&self, length: WordLength, rng: &mut R) -> String {
+        let mut word = Vec::<char>::new();
+        match length {
+            WordLength::Syllables(i) => {
+                // Samplers built without vowels never add a syllable, so give up at a length no real word reaches.
+                let syllables = (i as usize).max(1);
+                while vowel_groups(&word) < syllables
+                    && word.len() < syllables * MAX_SYLLABLE_LETTERS
+                {
+                    word.push(self.next(&word, rng));
+                }
+            }
+            WordLength::Chars(i) => {
+                while word.len() < (i as usize).max(1) {
+                    word.push(self.next(&word, rng));
+                }
+            }
+            WordLength::None => {
+                while word.len() < 7 {
+                    word.push(self.next(&word, rng));
+                }
+            }
+        }
+        word.into_iter().collect()
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::syllable_count;
+
+    #[test]
+    fn trigraph_test() {
+        let mut rng = rand::thread_rng();
+        let tb = TrigraphBuilder::from_text("banana").unwrap();
+
+        for _ in 0..100 {
+            let word = tb.build_length(WordLength::Chars(6), &mut rng);
+            assert_eq!(word.len(), 6);
+            // Every n-gram in the training text alternates 'a' with a consonant.
+            for pair in word.as_bytes().windows(2) {
+                assert!((pair[0] == b'a') != (pair[1] == b'a'), "{}", word);
+            }
+        }
+
+        let word = tb.build_length(WordLength::Syllables(3), &mut rng);
+        assert_eq!(syllable_count(&word), 3, "{}", word);
+    }
+
+    #[test]
+    fn untrainable_text_test() {
+        let mut rng = rand::thread_rng();
+        assert!(matches!(
+            TrigraphBuilder::from_text(""),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            TrigraphBuilder::from_text("an ox"),
+            Err(Error::EmptySampler)
+        ));
+        assert!(matches!(
+            TrigraphBuilder::from_text("psst, shh, hmm"),
+            Err(Error::NoVowels)
+        ));
+
+        // Samplers without vowels can still be given directly, but can't loop forever looking for a syllable.
+        let analysis = analyze("psst, shh, hmm");
+        let tb = TrigraphBuilder::from_samplers(
+            NGramSampler::new(analysis.letters),
+            NGramSampler::new(analysis.digraphs),
+            NGramSampler::new(analysis.trigraphs),
+        );
+        let word = tb.build_length(WordLength::Syllables(2), &mut rng);
+        assert_eq!(word.len(), 2 * MAX_SYLLABLE_LETTERS);
+    }
+
+    #[test]
+    fn sample_given_test() {
+        let mut rng = rand::thread_rng();
+        let tb = TrigraphBuilder::from_text("banana").unwrap();
+        for _ in 0..10 {
+            let trigraph = tb.trigraphs.sample_given(&['a', 'n'], &mut rng).unwrap();
+            assert_eq!(trigraph.chars, ['a', 'n', 'a']);
+        }
+        assert!(tb.trigraphs.sample_given(&['x'], &mut rng).is_none());
+    }
+}